@@ -23,13 +23,16 @@ pub fn param_struct(attr: TokenStream, item: TokenStream) -> TokenStream {
             let mut all_types = proc_macro2::TokenStream::new();
             let mut value_all_fields = proc_macro2::TokenStream::new();
             let mut all_fields = proc_macro2::TokenStream::new();
+            let mut tuple_all_fields = proc_macro2::TokenStream::new();
             let mut default_field_values = proc_macro2::TokenStream::new();
-            for (value, field) in default_values.zip(fields) {
+            for (i, (value, field)) in default_values.zip(fields).enumerate() {
                 let ty = &field.ty;
                 let ident = &field.ident;
+                let index = syn::Index::from(i);
                 all_types.extend(quote! { #ty, });
                 value_all_fields.extend(quote! { value.#ident, });
                 all_fields.extend(quote! {#ident, });
+                tuple_all_fields.extend(quote! { #ident : value.#index, });
                 default_field_values.extend(quote! { #ident : #value, });
             }
             quote! {
@@ -43,6 +46,18 @@ pub fn param_struct(attr: TokenStream, item: TokenStream) -> TokenStream {
                     }
                 }
 
+                impl From<(#all_types)> for #struct_identifier {
+                    fn from(value : (#all_types)) -> #struct_identifier {
+                        #struct_identifier {
+                            #tuple_all_fields
+                        }
+                    }
+                }
+
+                impl ::rusty_wilko::optimization::ParamStruct for #struct_identifier {
+                    type Tuple = (#all_types);
+                }
+
                 impl Default for #struct_identifier {
                     fn default() -> Self {
                         #struct_identifier {
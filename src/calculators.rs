@@ -1,5 +1,8 @@
+use std::fmt::Debug;
 use std::ops::Div;
-use num::{complex::ComplexFloat, Complex, Float, NumCast, Zero};
+use num::{Complex, Float, Num, NumCast, Zero};
+
+use crate::with_error::WithError;
 
 pub fn iterative_calculation<TAbs, TValue, TFunc>(error_tolerence : TAbs, n_0 : i32, delta_n : i32, mut term_function : TFunc) -> TValue
     where TAbs : Float, 
@@ -21,23 +24,103 @@ pub fn iterative_calculation<TAbs, TValue, TFunc>(error_tolerence : TAbs, n_0 :
     current_value
 }
 
-pub fn polynomial_ratio<const N : usize, TNum, TCoefficient>(numerator_coefficients : &[TCoefficient; N], denominator_coefficients : &[TCoefficient; N], x : TNum) -> (TNum, TNum)
-    where TNum : ComplexFloat,
-          TCoefficient : Copy + NumCast {
-        let (numerator, denominator, highest_power) = numerator_coefficients.iter()
-            .zip(denominator_coefficients)
-            .skip(1)
-            .fold((TNum::one() * NumCast::from(numerator_coefficients[0]).unwrap(), TNum::one() * NumCast::from(denominator_coefficients[0]).unwrap(), TNum::one()), 
-                |(cumulative_numerator, cumulative_denominator, current_x_power), (current_numerator, current_denominator)| {
-                let new_x_power = current_x_power * x;
-                (
-                    cumulative_numerator + new_x_power * NumCast::from(*current_numerator).unwrap(),
-                    cumulative_denominator + new_x_power * NumCast::from(*current_denominator).unwrap(),
-                    new_x_power
-                )
-            });
-
-        return (numerator / denominator, highest_power);
+/// Sums an asymptotic series with optimal truncation, adding terms until the next one would be no smaller than the last, or until they fall below `error_tolerance` relative to the sum.
+/// The error is the magnitude of the first term left out, which bounds the truncation error of the series usually met in practice.
+pub fn asymptotic_series<TValue, TFunc>(error_tolerance : f64, n_0 : i32, max_terms : i32, mut term_function : TFunc) -> WithError<TValue>
+    where TValue : NormSquared<f64> + Zero + Copy + Debug,
+          TFunc : FnMut(i32) -> TValue
+{
+    let error_tolerance_squared = error_tolerance * error_tolerance;
+    let mut current_value = TValue::zero();
+    let mut current_term = term_function(n_0);
+    for n in n_0 + 1..n_0 + max_terms {
+        let next_term = term_function(n);
+        if next_term.norm_squared() >= current_term.norm_squared() {
+            break;
+        }
+
+        current_value = current_value + current_term;
+        current_term = next_term;
+        if current_term.norm_squared() <= error_tolerance_squared * current_value.norm_squared() {
+            break;
+        }
+    }
+
+    WithError {
+        value : current_value,
+        error : current_term.norm_squared().sqrt(),
+    }
+}
+
+/// Evaluates the continued fraction b_0 + a_1 / (b_1 + a_2 / (b_2 + ...)) by the modified Lentz method, where `term_function(n)` gives (a_n, b_n) and a_0 is ignored.
+/// Levels are added until one changes the value by less than `error_tolerance` relatively, or until there have been `max_terms` of them.
+pub fn continued_fraction<TValue, TFunc>(error_tolerance : f64, max_terms : i32, mut term_function : TFunc) -> TValue
+    where TValue : NormSquared<f64> + Num + NumCast + Copy,
+          TFunc : FnMut(i32) -> (TValue, TValue)
+{
+    // Stands in for zero denominators, which the method otherwise divides by
+    let tiny = <TValue as NumCast>::from(1e-300).unwrap();
+    let error_tolerance_squared = error_tolerance * error_tolerance;
+
+    let b_0 = term_function(0).1;
+    let mut value = if b_0.is_zero() { tiny } else { b_0 };
+    let mut c = value;
+    let mut d = TValue::zero();
+    for n in 1..max_terms {
+        let (a, b) = term_function(n);
+        d = b + a * d;
+        if d.is_zero() {
+            d = tiny;
+        }
+        c = b + a / c;
+        if c.is_zero() {
+            c = tiny;
+        }
+
+        d = TValue::one() / d;
+        let delta = c * d;
+        value = value * delta;
+        if (delta - TValue::one()).norm_squared() <= error_tolerance_squared {
+            break;
+        }
+    }
+
+    value
+}
+
+/// Solves the square system matrix * x = rhs by Gaussian elimination with partial pivoting, returning None if the matrix is singular
+pub(crate) fn solve_linear_system(mut matrix : Vec<Vec<f64>>, mut rhs : Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+    for column in 0..size {
+        let pivot_row = (column..size).max_by(|a, b| matrix[*a][column].abs().total_cmp(&matrix[*b][column].abs()))?;
+        if matrix[pivot_row][column] == 0.0 || !matrix[pivot_row][column].is_finite() {
+            return None;
+        }
+
+        matrix.swap(column, pivot_row);
+        rhs.swap(column, pivot_row);
+
+        let pivot = matrix[column].clone();
+        for row in column + 1..size {
+            let factor = matrix[row][column] / pivot[column];
+            if factor == 0.0 {
+                continue;
+            }
+
+            for (entry, pivot_entry) in matrix[row][column..].iter_mut().zip(&pivot[column..]) {
+                *entry -= factor * pivot_entry;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let known = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum::<f64>();
+        solution[row] = (rhs[row] - known) / matrix[row][row];
+    }
+
+    Some(solution)
 }
 
 pub trait NormSquared<TAbs : Float> {
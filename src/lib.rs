@@ -1,18 +1,22 @@
 extern crate num;
 extern crate param_struct;
+// Lets code generated by param_struct name this crate the same way from inside and outside of it
+extern crate self as rusty_wilko;
 
 mod data_io;
 mod calculators;
 pub mod integration;
 pub mod special_functions;
 pub mod domain;
+pub mod polynomials;
+pub mod optimization;
 pub mod with_error;
 
 use std::fmt::Debug;
 use domain::Domain;
 pub use param_struct::param_struct;
 pub use data_io::{Savable, Plot, PRESENTATION_STYLE, PAPER_STYLE, LIGHT, DARK, Scale, Numpy, save};
-pub use calculators::iterative_calculation;
+pub use calculators::{iterative_calculation, asymptotic_series, continued_fraction};
 
 pub const EM_GAMMA : f64 = 0.577215664901532860606512090082402431_f64;
 
@@ -0,0 +1,141 @@
+use crate::domain::{Domain, OneDimensionalDomain};
+use super::Extremum;
+
+const MAX_ITERATIONS : usize = 200;
+
+// The fraction of an interval taken by the smaller part of its golden section, (3 - sqrt(5)) / 2
+const GOLDEN_SECTION : f64 = 0.381966011250105;
+
+/// Minimises a function of one variable on [lower_limit, upper_limit] by Brent's method, which takes parabolic interpolation steps where they behave and golden-section steps where they do not.
+/// `tolerance` is the relative precision of the location, below about the square root of machine epsilon it cannot be met.
+pub fn brent_minimum<TFunc>(function : TFunc, lower_limit : f64, upper_limit : f64, tolerance : f64) -> Extremum<f64>
+    where TFunc : Fn(f64) -> f64 {
+    let absolute_tolerance = f64::EPSILON * (upper_limit - lower_limit).abs();
+    let (mut a, mut b) = (lower_limit.min(upper_limit), lower_limit.max(upper_limit));
+    let mut x = a + GOLDEN_SECTION * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut function_x = function(x);
+    let (mut function_w, mut function_v) = (function_x, function_x);
+    let mut num_function_evaluations = 1;
+
+    // The step taken on this iteration, and the one before the last, which parabolic steps must beat by half to be trusted
+    let mut step : f64 = 0.0;
+    let mut previous_step : f64 = 0.0;
+
+    for _ in 0..MAX_ITERATIONS {
+        let midpoint = 0.5 * (a + b);
+        let tolerance_1 = tolerance * x.abs() + absolute_tolerance;
+        let tolerance_2 = 2.0 * tolerance_1;
+        if (x - midpoint).abs() <= tolerance_2 - 0.5 * (b - a) {
+            break;
+        }
+
+        let mut parabolic_step_accepted = false;
+        if previous_step.abs() > tolerance_1 {
+            let r = (x - w) * (function_x - function_v);
+            let mut q = (x - v) * (function_x - function_w);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            }
+            q = q.abs();
+
+            let step_before_last = previous_step;
+            previous_step = step;
+            if p.abs() < (0.5 * q * step_before_last).abs() && p > q * (a - x) && p < q * (b - x) {
+                step = p / q;
+                let u = x + step;
+                if u - a < tolerance_2 || b - u < tolerance_2 {
+                    step = tolerance_1.copysign(midpoint - x);
+                }
+                parabolic_step_accepted = true;
+            }
+        }
+
+        if !parabolic_step_accepted {
+            previous_step = if x >= midpoint { a - x } else { b - x };
+            step = GOLDEN_SECTION * previous_step;
+        }
+
+        let u = if step.abs() >= tolerance_1 { x + step } else { x + tolerance_1.copysign(step) };
+        let function_u = function(u);
+        num_function_evaluations += 1;
+
+        if function_u <= function_x {
+            if u >= x { a = x } else { b = x }
+            (v, w, x) = (w, x, u);
+            (function_v, function_w, function_x) = (function_w, function_x, function_u);
+        }
+        else {
+            if u < x { a = u } else { b = u }
+            if function_u <= function_w || w == x {
+                (v, w) = (w, u);
+                (function_v, function_w) = (function_w, function_u);
+            }
+            else if function_u <= function_v || v == x || v == w {
+                v = u;
+                function_v = function_u;
+            }
+        }
+    }
+
+    Extremum { location : x, value : function_x, num_function_evaluations }
+}
+
+/// Maximises a function of one variable on [lower_limit, upper_limit] by Brent's method
+pub fn brent_maximum<TFunc>(function : TFunc, lower_limit : f64, upper_limit : f64, tolerance : f64) -> Extremum<f64>
+    where TFunc : Fn(f64) -> f64 {
+    let minimum = brent_minimum(|x| -function(x), lower_limit, upper_limit, tolerance);
+    Extremum { value : -minimum.value, ..minimum }
+}
+
+/// The smallest value of `function` over a domain. The domain is searched point by point, in parallel, and the best point is refined by Brent's method between its neighbours.
+pub fn minimum_over_domain<TFunc, TParams>(function : TFunc, domain : &OneDimensionalDomain, parameters : &TParams, tolerance : f64) -> Extremum<f64>
+    where TFunc : Fn(f64, &TParams) -> f64 + Sync,
+          TParams : Sync {
+    let values = domain.execute_map(&function, parameters);
+    let best = (0..values.len()).min_by(|a, b| values[*a].total_cmp(&values[*b])).expect("The domain must not be empty");
+
+    let lower_limit = domain.values[best.saturating_sub(1)];
+    let upper_limit = domain.values[(best + 1).min(values.len() - 1)];
+    let refined = brent_minimum(|x| function(x, parameters), lower_limit, upper_limit, tolerance);
+    let num_function_evaluations = values.len() + refined.num_function_evaluations;
+
+    if refined.value < values[best] {
+        Extremum { num_function_evaluations, ..refined }
+    }
+    else {
+        Extremum { location : domain.values[best], value : values[best], num_function_evaluations }
+    }
+}
+
+/// The largest value of `function` over a domain, found as in `minimum_over_domain`
+pub fn maximum_over_domain<TFunc, TParams>(function : TFunc, domain : &OneDimensionalDomain, parameters : &TParams, tolerance : f64) -> Extremum<f64>
+    where TFunc : Fn(f64, &TParams) -> f64 + Sync,
+          TParams : Sync {
+    let minimum = minimum_over_domain(|x, p : &TParams| -function(x, p), domain, parameters, tolerance);
+    Extremum { value : -minimum.value, ..minimum }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent_minimum, maximum_over_domain};
+    use crate::domain::OneDimensionalDomain;
+
+    #[test]
+    fn brent_finds_minimum_of_quartic() {
+        let minimum = brent_minimum(|x| (x - 1.3).powi(4) + 0.5 * (x - 1.3).powi(2) - 2.0, -4.0, 7.0, 1e-10);
+        assert!((minimum.location - 1.3).abs() < 1e-8);
+        assert!((minimum.value + 2.0).abs() < 1e-15);
+        assert!(minimum.num_function_evaluations < 60);
+    }
+
+    #[test]
+    fn maximum_over_coarse_domain_is_refined() {
+        let domain = OneDimensionalDomain::new(0.0, 3.0, 7);
+        let maximum = maximum_over_domain(|x, width : &f64| (-(x - 1.234).powi(2) / width).exp(), &domain, &0.3, 1e-10);
+        assert!((maximum.location - 1.234).abs() < 1e-7);
+        assert!((maximum.value - 1.0).abs() < 1e-14);
+    }
+}
@@ -0,0 +1,7 @@
+/// The location and value of an extremum found by one of the optimizers, with the number of times the function was evaluated to find it
+#[derive(Clone, Debug)]
+pub struct Extremum<T> {
+    pub location : T,
+    pub value : f64,
+    pub num_function_evaluations : usize,
+}
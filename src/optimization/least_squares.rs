@@ -0,0 +1,194 @@
+use crate::calculators::solve_linear_system;
+use crate::domain::{CalculationResults, OneDimensionalDomain};
+use crate::with_error::WithError;
+use super::ParameterSet;
+
+const MAX_ITERATIONS : usize = 200;
+const INITIAL_DAMPING : f64 = 1e-3;
+const MAX_DAMPING : f64 = 1e16;
+const RELATIVE_TOLERANCE : f64 = 1e-12;
+
+/// The outcome of a least-squares fit
+#[derive(Clone, Debug)]
+pub struct Fit<TParams> {
+    pub parameters : TParams,
+    /// Each fitted parameter, in field order, with its standard error taken from the covariance matrix
+    pub parameters_with_error : Vec<WithError<f64>>,
+    pub covariance : Vec<Vec<f64>>,
+    pub residual_sum_of_squares : f64,
+    pub iterations : usize,
+}
+
+/// Fits `model` to the observed values at the points of `domain` by Levenberg–Marquardt, starting from `initial_parameters`.
+/// The covariance is the inverse of J^T J scaled by the residual variance, so the uncertainties assume independent errors of equal size.
+pub fn least_squares_fit<TFunc, TParams>(model : TFunc, domain : &OneDimensionalDomain, observed : &[f64], initial_parameters : &TParams) -> Fit<TParams>
+    where TFunc : Fn(f64, &TParams) -> f64,
+          TParams : ParameterSet {
+    assert_eq!(domain.values.len(), observed.len(), "There must be one observed value for each point in the domain");
+
+    let model_values = |parameters : &[f64]| {
+        let parameters = TParams::from_parameters(parameters);
+        domain.values.iter().map(|x| model(*x, &parameters)).collect::<Vec<f64>>()
+    };
+    let residuals = |parameters : &[f64]| model_values(parameters).iter().zip(observed).map(|(m, y)| y - m).collect::<Vec<f64>>();
+    let sum_of_squares = |residuals : &[f64]| residuals.iter().map(|r| r * r).sum::<f64>();
+
+    let mut parameters = initial_parameters.to_parameters();
+    let mut current_residuals = residuals(&parameters);
+    let mut cost = sum_of_squares(&current_residuals);
+    let mut damping = INITIAL_DAMPING;
+    let mut iterations = 0;
+    let mut jacobian = central_difference_jacobian(&model_values, &parameters);
+
+    while iterations < MAX_ITERATIONS {
+        iterations += 1;
+        let (normal_matrix, gradient) = normal_equations(&jacobian, &current_residuals);
+
+        let mut improved = None;
+        while damping < MAX_DAMPING {
+            let damped_matrix = normal_matrix.iter()
+                .enumerate()
+                .map(|(i, row)| row.iter().enumerate().map(|(j, a)| if i == j { a + damping * a.max(f64::MIN_POSITIVE) } else { *a }).collect())
+                .collect();
+
+            if let Some(step) = solve_linear_system(damped_matrix, gradient.clone()) {
+                let trial_parameters = parameters.iter().zip(&step).map(|(p, s)| p + s).collect::<Vec<f64>>();
+                let trial_residuals = residuals(&trial_parameters);
+                let trial_cost = sum_of_squares(&trial_residuals);
+                if trial_cost < cost {
+                    improved = Some((trial_parameters, trial_residuals, trial_cost));
+                    damping = (damping / 10.0).max(f64::EPSILON);
+                    break;
+                }
+            }
+
+            damping *= 10.0;
+        }
+
+        let Some((new_parameters, new_residuals, new_cost)) = improved else {
+            break;
+        };
+
+        let converged = cost - new_cost <= RELATIVE_TOLERANCE * cost
+            || new_parameters.iter().zip(&parameters).all(|(new, old)| (new - old).abs() <= RELATIVE_TOLERANCE * old.abs().max(f64::MIN_POSITIVE));
+        parameters = new_parameters;
+        current_residuals = new_residuals;
+        cost = new_cost;
+        jacobian = central_difference_jacobian(&model_values, &parameters);
+
+        if converged {
+            break;
+        }
+    }
+
+    let (normal_matrix, _) = normal_equations(&jacobian, &current_residuals);
+    let degrees_of_freedom = observed.len().saturating_sub(parameters.len());
+    let residual_variance = if degrees_of_freedom > 0 { cost / degrees_of_freedom as f64 } else { f64::NAN };
+    let covariance = (0..parameters.len())
+        .map(|j| {
+            let unit = (0..parameters.len()).map(|i| if i == j { 1.0 } else { 0.0 }).collect();
+            solve_linear_system(normal_matrix.clone(), unit)
+                .map_or(vec![f64::NAN; parameters.len()], |column| column.into_iter().map(|c| c * residual_variance).collect())
+        })
+        .collect::<Vec<Vec<f64>>>();
+
+    Fit {
+        parameters_with_error : parameters.iter()
+            .enumerate()
+            .map(|(j, p)| WithError { value : *p, error : covariance[j][j].sqrt() })
+            .collect(),
+        parameters : TParams::from_parameters(&parameters),
+        covariance,
+        residual_sum_of_squares : cost,
+        iterations,
+    }
+}
+
+/// Fits `model` separately to each of the results of a calculation over a one dimensional domain
+pub fn least_squares_fit_results<TFunc, TParams, const N : usize>(model : TFunc, results : &CalculationResults<OneDimensionalDomain, f64, N>, initial_parameters : &TParams) -> Vec<Fit<TParams>>
+    where TFunc : Fn(f64, &TParams) -> f64,
+          TParams : ParameterSet {
+    results.results.iter()
+        .map(|observed| least_squares_fit(&model, results.domain_data, observed, initial_parameters))
+        .collect()
+}
+
+/// The derivatives of the model values with respect to each parameter, as rows of the Jacobian indexed by domain point
+fn central_difference_jacobian<TFunc>(model_values : &TFunc, parameters : &[f64]) -> Vec<Vec<f64>>
+    where TFunc : Fn(&[f64]) -> Vec<f64> {
+    let columns = (0..parameters.len())
+        .map(|j| {
+            let step = f64::EPSILON.cbrt() * parameters[j].abs().max(1.0);
+            let mut shifted = parameters.to_vec();
+            shifted[j] = parameters[j] + step;
+            let above = model_values(&shifted);
+            shifted[j] = parameters[j] - step;
+            let below = model_values(&shifted);
+            above.iter().zip(below).map(|(a, b)| (a - b) / (2.0 * step)).collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+
+    (0..columns.first().map_or(0, Vec::len))
+        .map(|i| columns.iter().map(|column| column[i]).collect())
+        .collect()
+}
+
+/// J^T J and J^T r
+fn normal_equations(jacobian : &[Vec<f64>], residuals : &[f64]) -> (Vec<Vec<f64>>, Vec<f64>) {
+    let parameter_count = jacobian.first().map_or(0, Vec::len);
+    let normal_matrix = (0..parameter_count)
+        .map(|a| (0..parameter_count).map(|b| jacobian.iter().map(|row| row[a] * row[b]).sum()).collect())
+        .collect();
+    let gradient = (0..parameter_count)
+        .map(|a| jacobian.iter().zip(residuals).map(|(row, r)| row[a] * r).sum())
+        .collect();
+
+    (normal_matrix, gradient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{least_squares_fit, least_squares_fit_results};
+    use crate::domain::{Domain, OneDimensionalDomain};
+    use crate::param_struct;
+
+    #[param_struct(1.0, 1.0, 0.0)]
+    #[derive(Debug, Clone)]
+    struct Decay {
+        amplitude : f64,
+        rate : f64,
+        offset : f64,
+    }
+
+    fn decay(x : f64, parameters : &Decay) -> f64 {
+        parameters.amplitude * (-parameters.rate * x).exp() + parameters.offset
+    }
+
+    #[test]
+    fn fits_exponential_decay() {
+        let domain = OneDimensionalDomain::new(0.0, 5.0, 60);
+        let truth = Decay { amplitude : 2.5, rate : 0.7, offset : 0.3 };
+        let observed = domain.values.iter().map(|x| decay(*x, &truth) + 1e-3 * (37.0 * x).sin()).collect::<Vec<f64>>();
+
+        let fit = least_squares_fit(decay, &domain, &observed, &Decay::default());
+        for (fitted, expected) in fit.parameters_with_error.iter().zip([2.5, 0.7, 0.3]) {
+            assert!(fitted.error > 0.0 && fitted.error < 1e-2);
+            assert!((fitted.value - expected).abs() < 5.0 * fitted.error, "{fitted:?}");
+        }
+        assert!((fit.parameters.rate - 0.7).abs() < 1e-2);
+    }
+
+    #[test]
+    fn fits_each_calculation_result() {
+        let domain = OneDimensionalDomain::new(0.0, 4.0, 40);
+        let results = domain.execute_multimap(decay, [
+            ("slow".to_owned(), Decay { amplitude : 1.0, rate : 0.2, offset : 0.0 }),
+            ("fast".to_owned(), Decay { amplitude : 3.0, rate : 2.0, offset : 1.0 }),
+        ]);
+
+        let fits = least_squares_fit_results(decay, &results, &Decay::default());
+        assert!((fits[0].parameters.rate - 0.2).abs() < 1e-8);
+        assert!((fits[1].parameters.rate - 2.0).abs() < 1e-8);
+        assert!(fits[1].residual_sum_of_squares < 1e-20);
+    }
+}
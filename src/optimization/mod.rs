@@ -0,0 +1,11 @@
+mod parameters;
+mod extremum;
+mod least_squares;
+mod nelder_mead;
+mod brent;
+
+pub use self::parameters::{ParamStruct, ParameterSet, ParameterTuple};
+pub use self::extremum::Extremum;
+pub use self::least_squares::{least_squares_fit, least_squares_fit_results, Fit};
+pub use self::nelder_mead::nelder_mead;
+pub use self::brent::{brent_minimum, brent_maximum, minimum_over_domain, maximum_over_domain};
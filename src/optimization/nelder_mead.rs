@@ -0,0 +1,119 @@
+use std::cell::Cell;
+
+use super::{Extremum, ParameterSet};
+
+const MAX_EVALUATIONS_PER_PARAMETER : usize = 2000;
+
+// Standard reflection, expansion, contraction and shrink coefficients
+const REFLECTION : f64 = 1.0;
+const EXPANSION : f64 = 2.0;
+const CONTRACTION : f64 = 0.5;
+const SHRINK : f64 = 0.5;
+
+/// Minimises `function` over a set of parameters by the Nelder–Mead downhill simplex method, which needs no derivatives.
+/// The initial simplex steps each parameter by 5% of its initial value. The search stops when the function values across the simplex agree to within `tolerance`, relatively.
+pub fn nelder_mead<TFunc, TParams>(function : TFunc, initial_parameters : &TParams, tolerance : f64) -> Extremum<TParams>
+    where TFunc : Fn(&TParams) -> f64,
+          TParams : ParameterSet {
+    let num_function_evaluations = Cell::new(0);
+    let evaluate = |point : &[f64]| {
+        num_function_evaluations.set(num_function_evaluations.get() + 1);
+        function(&TParams::from_parameters(point))
+    };
+
+    let initial_point = initial_parameters.to_parameters();
+    let dimension = initial_point.len();
+
+    let mut simplex = vec![initial_point.clone()];
+    for i in 0..dimension {
+        let mut vertex = initial_point.clone();
+        vertex[i] = if vertex[i] != 0.0 { 1.05 * vertex[i] } else { 0.00025 };
+        simplex.push(vertex);
+    }
+    let mut values = simplex.iter().map(|vertex| evaluate(vertex)).collect::<Vec<f64>>();
+
+    let max_evaluations = MAX_EVALUATIONS_PER_PARAMETER * dimension.max(1);
+    while num_function_evaluations.get() < max_evaluations {
+        let mut order = (0..=dimension).collect::<Vec<usize>>();
+        order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+        simplex = order.iter().map(|i| simplex[*i].clone()).collect();
+        values = order.iter().map(|i| values[*i]).collect();
+
+        let (best, worst) = (values[0], values[dimension]);
+        if (worst - best).abs() <= tolerance * (best.abs() + worst.abs()) + f64::MIN_POSITIVE {
+            break;
+        }
+
+        let centroid = (0..dimension)
+            .map(|i| simplex[..dimension].iter().map(|vertex| vertex[i]).sum::<f64>() / dimension as f64)
+            .collect::<Vec<f64>>();
+        let along_worst = |coefficient : f64| centroid.iter()
+            .zip(&simplex[dimension])
+            .map(|(c, w)| c + coefficient * (c - w))
+            .collect::<Vec<f64>>();
+
+        let reflected = along_worst(REFLECTION);
+        let reflected_value = evaluate(&reflected);
+
+        if reflected_value < best {
+            let expanded = along_worst(EXPANSION);
+            let expanded_value = evaluate(&expanded);
+            (simplex[dimension], values[dimension]) = if expanded_value < reflected_value { (expanded, expanded_value) } else { (reflected, reflected_value) };
+            continue;
+        }
+
+        if reflected_value < values[dimension - 1] {
+            (simplex[dimension], values[dimension]) = (reflected, reflected_value);
+            continue;
+        }
+
+        // Contract towards the better of the worst and reflected points
+        let contracted = if reflected_value < worst { along_worst(CONTRACTION * REFLECTION) } else { along_worst(-CONTRACTION) };
+        let contracted_value = evaluate(&contracted);
+        if contracted_value < reflected_value.min(worst) {
+            (simplex[dimension], values[dimension]) = (contracted, contracted_value);
+            continue;
+        }
+
+        for i in 1..=dimension {
+            simplex[i] = simplex[0].iter().zip(&simplex[i]).map(|(b, v)| b + SHRINK * (v - b)).collect();
+            values[i] = evaluate(&simplex[i]);
+        }
+    }
+
+    let best = (0..=dimension).min_by(|a, b| values[*a].total_cmp(&values[*b])).unwrap();
+    Extremum {
+        location : TParams::from_parameters(&simplex[best]),
+        value : values[best],
+        num_function_evaluations : num_function_evaluations.get(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nelder_mead;
+    use crate::param_struct;
+
+    #[param_struct(0.0, 0.0)]
+    #[derive(Debug)]
+    struct Point {
+        x : f64,
+        y : f64,
+    }
+
+    #[test]
+    fn minimises_rosenbrock() {
+        let rosenbrock = |p : &Point| (1.0 - p.x).powi(2) + 100.0 * (p.y - p.x * p.x).powi(2);
+        let minimum = nelder_mead(rosenbrock, &Point { x : -1.2, y : 1.0 }, 1e-14);
+        assert!((minimum.location.x - 1.0).abs() < 1e-5, "{minimum:?}");
+        assert!((minimum.location.y - 1.0).abs() < 1e-5, "{minimum:?}");
+        assert!(minimum.value < 1e-10);
+    }
+
+    #[test]
+    fn minimises_over_parameters_at_fixed_x() {
+        let model = |x : f64, p : &Point| (p.x * x - 3.0).powi(2) + (p.y - x).powi(2);
+        let minimum = nelder_mead(|p| model(2.0, p), &Point::default(), 1e-14);
+        assert!((minimum.location.x - 1.5).abs() < 1e-5 && (minimum.location.y - 2.0).abs() < 1e-5);
+    }
+}
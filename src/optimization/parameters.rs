@@ -0,0 +1,56 @@
+/// Implemented by the `param_struct` macro, naming the tuple type that the struct converts to and from
+pub trait ParamStruct {
+    type Tuple;
+}
+
+/// A set of real parameters that optimizers and fitters can vary, by flattening it to a vector and rebuilding it from one
+pub trait ParameterSet : Sized {
+    fn to_parameters(&self) -> Vec<f64>;
+    fn from_parameters(parameters : &[f64]) -> Self;
+}
+
+/// Tuples of f64, through which `param_struct` structs are flattened
+pub trait ParameterTuple : Sized {
+    fn to_parameters(&self) -> Vec<f64>;
+    fn from_parameters(parameters : &[f64]) -> Self;
+}
+
+impl<TParams> ParameterSet for TParams
+    where TParams : ParamStruct + From<<TParams as ParamStruct>::Tuple>,
+          <TParams as ParamStruct>::Tuple : ParameterTuple + for<'a> From<&'a TParams> {
+    fn to_parameters(&self) -> Vec<f64> {
+        <TParams as ParamStruct>::Tuple::from(self).to_parameters()
+    }
+
+    fn from_parameters(parameters : &[f64]) -> Self {
+        <<TParams as ParamStruct>::Tuple as ParameterTuple>::from_parameters(parameters).into()
+    }
+}
+
+macro_rules! parameter_tuple {
+    ($($index:tt),+) => {
+        impl ParameterTuple for ($(parameter_tuple!(@f64 $index),)+) {
+            fn to_parameters(&self) -> Vec<f64> {
+                vec![$(self.$index),+]
+            }
+
+            fn from_parameters(parameters : &[f64]) -> Self {
+                ($(parameters[$index],)+)
+            }
+        }
+    };
+    (@f64 $index:tt) => { f64 };
+}
+
+parameter_tuple!(0);
+parameter_tuple!(0, 1);
+parameter_tuple!(0, 1, 2);
+parameter_tuple!(0, 1, 2, 3);
+parameter_tuple!(0, 1, 2, 3, 4);
+parameter_tuple!(0, 1, 2, 3, 4, 5);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+parameter_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
@@ -0,0 +1,303 @@
+use std::f64::consts::PI;
+use std::ops::Mul;
+
+use num::complex::{Complex64, ComplexFloat};
+
+use super::polynomial::aberth_iteration;
+
+const MIN_FIT_INTERVALS_LOG2 : u32 = 4;
+const MAX_FIT_INTERVALS_LOG2 : u32 = 13;
+
+// Beyond this degree the series is split in two before root finding, which keeps Aberth iteration cheap and well conditioned
+const DIRECT_ROOT_FINDING_MAX_DEGREE : usize = 64;
+
+// Split slightly off centre, as chebfun does, so that roots of symmetric functions do not land on the split
+const ROOT_FINDING_SPLIT_POINT : f64 = -0.004849834917525;
+
+/// A truncated Chebyshev series, sum_k c_k T_k(t), on [lower_limit, upper_limit], where t maps that interval linearly onto [-1, 1]
+#[derive(Clone, Debug)]
+pub struct Chebyshev<T> {
+    pub coefficients : Vec<T>,
+    pub lower_limit : f64,
+    pub upper_limit : f64,
+}
+
+impl<T> Chebyshev<T>
+    where T : ComplexFloat<Real = f64> + Mul<f64, Output = T> {
+    /// Adaptively fits `function` on [lower_limit, upper_limit] by interpolating it at successively doubled grids of Chebyshev points,
+    /// until the trailing coefficients fall below `tolerance` relative to the largest sampled value. Each doubling reuses the previous samples.
+    /// If that has not happened by 2^13 intervals, the unconverged series on the finest grid is returned as the error.
+    pub fn fit<TFunc>(function : TFunc, lower_limit : f64, upper_limit : f64, tolerance : f64) -> Result<Chebyshev<T>, Chebyshev<T>>
+        where TFunc : Fn(f64) -> T {
+        let sample = |j : usize, intervals : usize| function(map_from_unit(chebyshev_point(j, intervals), lower_limit, upper_limit));
+
+        let mut intervals = 1 << MIN_FIT_INTERVALS_LOG2;
+        let mut values = (0..=intervals).map(|j| sample(j, intervals)).collect::<Vec<T>>();
+        loop {
+            let coefficients = interpolation_coefficients(&values);
+            let threshold = tolerance * values.iter().map(|v| v.abs()).fold(f64::MIN_POSITIVE, f64::max);
+
+            if coefficients[intervals - intervals / 4..].iter().all(|c| c.abs() <= threshold) {
+                return Ok(Chebyshev { coefficients : chop(coefficients, threshold), lower_limit, upper_limit });
+            }
+
+            if intervals >= 1 << MAX_FIT_INTERVALS_LOG2 {
+                return Err(Chebyshev { coefficients, lower_limit, upper_limit });
+            }
+
+            intervals *= 2;
+            values = (0..=intervals)
+                .map(|j| if j % 2 == 0 { values[j / 2] } else { sample(j, intervals) })
+                .collect();
+        }
+    }
+
+    pub fn from_coefficients(coefficients : Vec<T>, lower_limit : f64, upper_limit : f64) -> Chebyshev<T> {
+        Chebyshev { coefficients, lower_limit, upper_limit }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    /// Evaluates the series by Clenshaw's recurrence
+    pub fn evaluate(&self, x : f64) -> T {
+        clenshaw(&self.coefficients, map_to_unit(x, self.lower_limit, self.upper_limit))
+    }
+
+    pub fn derivative(&self) -> Chebyshev<T> {
+        let scale = 2.0 / (self.upper_limit - self.lower_limit);
+        Chebyshev {
+            coefficients : derivative_coefficients(&self.coefficients).into_iter().map(|c| c * scale).collect(),
+            lower_limit : self.lower_limit,
+            upper_limit : self.upper_limit,
+        }
+    }
+
+    /// The antiderivative which vanishes at the lower limit
+    pub fn integral(&self) -> Chebyshev<T> {
+        let n = self.coefficients.len();
+        let coefficient = |k : usize| if k < n { self.coefficients[k] } else { T::zero() };
+        let scale = 0.5 * (self.upper_limit - self.lower_limit);
+
+        let mut integral_coefficients = std::iter::once(T::zero())
+            .chain((1..=n).map(|k| {
+                let previous = if k == 1 { coefficient(0) * 2.0 } else { coefficient(k - 1) };
+                (previous - coefficient(k + 1)) * (scale / (2 * k) as f64)
+            }))
+            .collect::<Vec<T>>();
+
+        // T_k(-1) = (-1)^k, so this choice of constant term makes the antiderivative vanish at t = -1
+        integral_coefficients[0] = integral_coefficients.iter()
+            .enumerate()
+            .skip(1)
+            .fold(T::zero(), |sum, (k, c)| if k % 2 == 0 { sum - *c } else { sum + *c });
+
+        Chebyshev { coefficients : integral_coefficients, lower_limit : self.lower_limit, upper_limit : self.upper_limit }
+    }
+
+    /// The integral over the whole interval, using the integral of T_k over [-1, 1] being 2 / (1 - k^2) for even k and zero for odd k
+    pub fn definite_integral(&self) -> T {
+        self.coefficients.iter()
+            .enumerate()
+            .step_by(2)
+            .fold(T::zero(), |sum, (k, c)| sum + *c * (2.0 / (1.0 - (k * k) as f64)))
+            * (0.5 * (self.upper_limit - self.lower_limit))
+    }
+}
+
+impl Chebyshev<f64> {
+    /// The real roots inside the interval, in ascending order
+    pub fn roots(&self) -> Vec<f64> {
+        let mut roots = unit_roots(&self.coefficients).into_iter()
+            .map(|t| map_from_unit(t, self.lower_limit, self.upper_limit))
+            .collect::<Vec<f64>>();
+        roots.sort_by(|a, b| a.total_cmp(b));
+        roots.dedup_by(|a, b| (*a - *b).abs() <= 1e-12 * (self.upper_limit - self.lower_limit));
+        roots
+    }
+}
+
+/// The j-th of the n + 1 Chebyshev points of the second kind, cos(j pi / n), which run from 1 down to -1
+fn chebyshev_point(j : usize, intervals : usize) -> f64 {
+    (PI * j as f64 / intervals as f64).cos()
+}
+
+fn map_from_unit(t : f64, lower_limit : f64, upper_limit : f64) -> f64 {
+    0.5 * (upper_limit + lower_limit) + 0.5 * (upper_limit - lower_limit) * t
+}
+
+fn map_to_unit(x : f64, lower_limit : f64, upper_limit : f64) -> f64 {
+    (2.0 * x - lower_limit - upper_limit) / (upper_limit - lower_limit)
+}
+
+/// Coefficients of the polynomial interpolating `values` at the Chebyshev points of the second kind, by a direct type-I discrete cosine transform
+fn interpolation_coefficients<T>(values : &[T]) -> Vec<T>
+    where T : ComplexFloat + Mul<f64, Output = T> {
+    let intervals = values.len() - 1;
+    if intervals == 0 {
+        return values.to_vec();
+    }
+
+    let cosines = (0..2 * intervals).map(|m| chebyshev_point(m, intervals)).collect::<Vec<f64>>();
+    (0..=intervals)
+        .map(|k| {
+            let sum = values.iter()
+                .enumerate()
+                .fold(T::zero(), |sum, (j, v)| {
+                    let endpoint_weight = if j == 0 || j == intervals { 0.5 } else { 1.0 };
+                    sum + *v * (endpoint_weight * cosines[(j * k) % (2 * intervals)])
+                });
+            let endpoint_weight = if k == 0 || k == intervals { 0.5 } else { 1.0 };
+            sum * (2.0 * endpoint_weight / intervals as f64)
+        })
+        .collect()
+}
+
+/// Drops the trailing coefficients which are no larger than the threshold, keeping at least the constant term
+fn chop<T : ComplexFloat<Real = f64>>(mut coefficients : Vec<T>, threshold : f64) -> Vec<T> {
+    let length = coefficients.iter().rposition(|c| c.abs() > threshold).map_or(1, |i| i + 1);
+    coefficients.truncate(length);
+    coefficients
+}
+
+fn clenshaw<T>(coefficients : &[T], t : f64) -> T
+    where T : ComplexFloat + Mul<f64, Output = T> {
+    let (b_1, b_2) = coefficients.iter()
+        .skip(1)
+        .rev()
+        .fold((T::zero(), T::zero()), |(b_1, b_2), c| (*c + b_1 * (2.0 * t) - b_2, b_1));
+    coefficients.first().map_or(T::zero(), |c_0| *c_0 + b_1 * t - b_2)
+}
+
+fn complex_clenshaw(coefficients : &[f64], t : Complex64) -> Complex64 {
+    let (b_1, b_2) = coefficients.iter()
+        .skip(1)
+        .rev()
+        .fold((Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0)), |(b_1, b_2), c| (*c + b_1 * 2.0 * t - b_2, b_1));
+    coefficients.first().map_or(Complex64::new(0.0, 0.0), |c_0| *c_0 + b_1 * t - b_2)
+}
+
+/// Coefficients of the derivative with respect to t, from the recurrence c'_{k-1} = c'_{k+1} + 2k c_k
+fn derivative_coefficients<T>(coefficients : &[T]) -> Vec<T>
+    where T : ComplexFloat + Mul<f64, Output = T> {
+    let n = coefficients.len();
+    if n <= 1 {
+        return vec![T::zero()];
+    }
+
+    let mut derivative = vec![T::zero(); n + 1];
+    for k in (1..n).rev() {
+        derivative[k - 1] = derivative[k + 1] + coefficients[k] * (2 * k) as f64;
+    }
+    derivative[0] = derivative[0] * 0.5;
+    derivative.truncate(n - 1);
+    derivative
+}
+
+/// Real roots in [-1, 1] of a series in t
+fn unit_roots(coefficients : &[f64]) -> Vec<f64> {
+    let length = coefficients.iter().rposition(|c| *c != 0.0).map_or(0, |i| i + 1);
+    let coefficients = &coefficients[..length];
+    if coefficients.len() <= 1 {
+        return vec![];
+    }
+
+    let degree = coefficients.len() - 1;
+    if degree > DIRECT_ROOT_FINDING_MAX_DEGREE {
+        // Restricting a polynomial to a subinterval gives a polynomial of the same degree, so interpolating it there is exact
+        let scale = coefficients.iter().map(|c| c.abs()).fold(0.0, f64::max);
+        return [(-1.0, ROOT_FINDING_SPLIT_POINT), (ROOT_FINDING_SPLIT_POINT, 1.0)].iter()
+            .flat_map(|&(lower, upper)| {
+                let values = (0..=degree)
+                    .map(|j| clenshaw(coefficients, map_from_unit(chebyshev_point(j, degree), lower, upper)))
+                    .collect::<Vec<f64>>();
+                unit_roots(&chop(interpolation_coefficients(&values), f64::EPSILON * scale)).into_iter()
+                    .map(move |t| map_from_unit(t, lower, upper))
+            })
+            .collect();
+    }
+
+    let derivative = derivative_coefficients(coefficients);
+    let initial_estimates = (0..degree)
+        .map(|k| {
+            let angle = PI * (k as f64 + 0.5) / degree as f64;
+            Complex64::new(1.1 * angle.cos(), 0.3 * angle.sin())
+        })
+        .collect::<Vec<Complex64>>();
+
+    aberth_iteration(initial_estimates, |t| complex_clenshaw(coefficients, t) / complex_clenshaw(&derivative, t))
+        .into_iter()
+        .filter(|t| t.im.abs() <= 1e-8 && t.re.abs() <= 1.0 + 1e-8)
+        .map(|t| {
+            // Polish on the real line, where the series is real
+            let mut root = t.re.clamp(-1.0, 1.0);
+            for _ in 0..2 {
+                let step = clenshaw(coefficients, root) / clenshaw(&derivative, root);
+                if step.is_finite() {
+                    root = (root - step).clamp(-1.0, 1.0);
+                }
+            }
+            root
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::Chebyshev;
+    use crate::domain::{Domain, OneDimensionalDomain};
+    use num::complex::Complex64;
+
+    #[test]
+    fn exponential_to_machine_precision() {
+        let series = Chebyshev::fit(f64::exp, 0.0, 2.0, 1e-15).unwrap();
+        assert!(series.degree() < 30);
+        for x in [0.0, 0.3, 1.7, 2.0] {
+            assert!((series.evaluate(x) - x.exp()).abs() < 1e-14);
+            assert!((series.derivative().evaluate(x) - x.exp()).abs() < 1e-12);
+            assert!((series.integral().evaluate(x) - (x.exp() - 1.0)).abs() < 1e-14);
+        }
+        assert!((series.definite_integral() - (2.0f64.exp() - 1.0)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn complex_valued_function() {
+        let series = Chebyshev::fit(|x| Complex64::new(0.0, x).exp(), -3.0, 3.0, 1e-14).unwrap();
+        assert!((series.evaluate(1.0) - Complex64::new(1.0f64.cos(), 1.0f64.sin())).norm() < 1e-13);
+        assert!((series.definite_integral() - 2.0 * 3.0f64.sin()).norm() < 1e-13);
+    }
+
+    #[test]
+    fn roots_of_high_degree_series() {
+        let series = Chebyshev::fit(f64::cos, 0.0, 100.0, 1e-15).unwrap();
+        assert!(series.degree() > 64);
+        let roots = series.roots();
+        assert_eq!(roots.len(), 32);
+        for (k, root) in roots.iter().enumerate() {
+            assert!((root - (k as f64 + 0.5) * PI).abs() < 1e-10, "{root}");
+        }
+    }
+
+    #[test]
+    fn reports_failure_to_converge() {
+        // The coefficients of |x| only decay like 1 / k^2, so machine precision is out of reach
+        let series = Chebyshev::fit(f64::abs, -1.0, 1.0, 1e-15).unwrap_err();
+        assert_eq!(series.degree(), 1 << 13);
+        for x in [-0.7, 0.0, 0.4] {
+            assert!((series.evaluate(x) - x.abs()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn plugs_into_execute_map() {
+        let series = Chebyshev::fit(|x : f64| x.sin() / (1.0 + x * x), -1.0, 1.0, 1e-15).unwrap();
+        let domain = OneDimensionalDomain::new(-1.0, 1.0, 21);
+        let results = domain.execute_map(|x, _| series.evaluate(x), &());
+        for (x, y) in domain.values.iter().zip(results) {
+            assert!((y - x.sin() / (1.0 + x * x)).abs() < 1e-14);
+        }
+    }
+}
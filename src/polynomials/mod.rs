@@ -0,0 +1,9 @@
+mod polynomial;
+mod rational;
+mod chebyshev;
+mod rational_approximation;
+
+pub use self::polynomial::Polynomial;
+pub use self::rational::Rational;
+pub use self::chebyshev::Chebyshev;
+pub use self::rational_approximation::{minimax, pade, ErrorMeasure, RationalApproximation};
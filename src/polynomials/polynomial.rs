@@ -0,0 +1,360 @@
+use std::borrow::Cow;
+use std::f64::consts::TAU;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::complex::{Complex64, ComplexFloat};
+use num::{Num, NumCast, Zero};
+
+const ROOT_FINDING_MAX_ITERATIONS : usize = 500;
+
+/// A polynomial in x, stored as its coefficients in ascending powers so that `coefficients[i]` multiplies x^i
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T : Clone + 'static> {
+    coefficients : Cow<'static, [T]>,
+}
+
+impl<T : Clone + 'static> Polynomial<T> {
+    /// Wraps a coefficient table without allocating, so that polynomials can be declared as constants
+    pub const fn from_static(coefficients : &'static [T]) -> Polynomial<T> {
+        Polynomial { coefficients : Cow::Borrowed(coefficients) }
+    }
+
+    pub fn new(coefficients : Vec<T>) -> Polynomial<T> {
+        Polynomial { coefficients : Cow::Owned(coefficients) }
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+}
+
+impl<T> Polynomial<T>
+    where T : Copy + NumCast + 'static {
+    /// Evaluates the polynomial using Horner's scheme
+    pub fn evaluate<TNum : ComplexFloat>(&self, x : TNum) -> TNum {
+        horner(&self.coefficients, x)
+    }
+
+    /// Evaluates the polynomial using Estrin's scheme, which has shorter dependency chains than Horner's scheme for high degrees
+    pub fn evaluate_estrin<TNum : ComplexFloat>(&self, x : TNum) -> TNum {
+        estrin(&self.coefficients, x)
+    }
+
+    /// All complex roots, repeated according to multiplicity, found by Aberth–Ehrlich iteration
+    pub fn roots(&self) -> Vec<Complex64> {
+        let coefficients = self.coefficients.iter()
+            .map(|c| Complex64::new(c.to_f64().unwrap(), 0.0))
+            .collect::<Vec<Complex64>>();
+
+        aberth_roots(&coefficients)
+    }
+
+    /// The real roots in ascending order, i.e. those roots whose imaginary part is negligible compared to their size
+    pub fn real_roots(&self) -> Vec<f64> {
+        let mut real_roots = self.roots().into_iter()
+            .filter(|root| root.im.abs() <= 1e-10 * root.norm().max(1.0))
+            .map(|root| root.re)
+            .collect::<Vec<f64>>();
+        real_roots.sort_by(|a, b| a.total_cmp(b));
+        real_roots
+    }
+}
+
+impl<T> Polynomial<T>
+    where T : Num + Copy + NumCast + 'static {
+    /// The degree, ignoring any zero coefficients of the highest powers. The zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.iter().rposition(|c| !c.is_zero()).unwrap_or(0)
+    }
+
+    pub fn derivative(&self) -> Polynomial<T> {
+        if self.coefficients.len() <= 1 {
+            return Polynomial::new(vec![T::zero()]);
+        }
+
+        Polynomial::new(self.coefficients.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, c)| *c * <T as NumCast>::from(power).unwrap())
+            .collect())
+    }
+
+    /// The antiderivative which vanishes at x = 0
+    pub fn integral(&self) -> Polynomial<T> {
+        Polynomial::new(std::iter::once(T::zero())
+            .chain(self.coefficients.iter()
+                .enumerate()
+                .map(|(power, c)| *c / <T as NumCast>::from(power + 1).unwrap()))
+            .collect())
+    }
+
+    pub fn scale(&self, c : T) -> Polynomial<T> {
+        Polynomial::new(self.coefficients.iter().map(|x| *x * c).collect())
+    }
+
+    /// Polynomial long division, returning the quotient and remainder, or None when the divisor is the zero polynomial
+    pub fn div_rem(&self, divisor : &Polynomial<T>) -> Option<(Polynomial<T>, Polynomial<T>)> {
+        let divisor_degree = divisor.degree();
+        let leading = divisor.coefficients.get(divisor_degree).copied().filter(|c| !c.is_zero())?;
+
+        // The zero polynomial may have no coefficients at all
+        let mut remainder = if self.coefficients.is_empty() { vec![T::zero()] } else { self.coefficients[..=self.degree()].to_vec() };
+        if remainder.len() <= divisor_degree {
+            return Some((Polynomial::new(vec![T::zero()]), Polynomial::new(remainder)));
+        }
+
+        let mut quotient = vec![T::zero(); remainder.len() - divisor_degree];
+        for power in (0..quotient.len()).rev() {
+            let factor = remainder[power + divisor_degree] / leading;
+            quotient[power] = factor;
+            for (i, d) in divisor.coefficients[..=divisor_degree].iter().enumerate() {
+                remainder[power + i] = remainder[power + i] - factor * *d;
+            }
+        }
+
+        remainder.truncate(divisor_degree.max(1));
+        Some((Polynomial::new(quotient), Polynomial::new(remainder)))
+    }
+}
+
+impl<T> Add for &Polynomial<T>
+    where T : Num + Copy + 'static {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs : Self) -> Polynomial<T> {
+        let length = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..length)
+            .map(|i| *self.coefficients.get(i).unwrap_or(&T::zero()) + *rhs.coefficients.get(i).unwrap_or(&T::zero()))
+            .collect())
+    }
+}
+
+impl<T> Sub for &Polynomial<T>
+    where T : Num + Copy + 'static {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs : Self) -> Polynomial<T> {
+        let length = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..length)
+            .map(|i| *self.coefficients.get(i).unwrap_or(&T::zero()) - *rhs.coefficients.get(i).unwrap_or(&T::zero()))
+            .collect())
+    }
+}
+
+impl<T> Mul for &Polynomial<T>
+    where T : Num + Copy + 'static {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs : Self) -> Polynomial<T> {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Polynomial::new(vec![T::zero()]);
+        }
+
+        let mut product = vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + *a * *b;
+            }
+        }
+
+        Polynomial::new(product)
+    }
+}
+
+impl<T> Neg for &Polynomial<T>
+    where T : Num + Copy + Neg<Output = T> + 'static {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial::new(self.coefficients.iter().map(|c| -*c).collect())
+    }
+}
+
+macro_rules! forward_owned_binary_operation {
+    ($trait:ident, $method:ident) => {
+        impl<T> $trait for Polynomial<T>
+            where T : Num + Copy + 'static {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs : Self) -> Polynomial<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_owned_binary_operation!(Add, add);
+forward_owned_binary_operation!(Sub, sub);
+forward_owned_binary_operation!(Mul, mul);
+
+impl<T> Neg for Polynomial<T>
+    where T : Num + Copy + Neg<Output = T> + 'static {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+/// Horner evaluation of coefficients given in ascending powers of x
+pub(crate) fn horner<TNum, TCoefficient>(coefficients : &[TCoefficient], x : TNum) -> TNum
+    where TNum : ComplexFloat,
+          TCoefficient : Copy + NumCast {
+    coefficients.iter()
+        .rev()
+        .fold(TNum::zero(), |accumulated, c| accumulated * x + <TNum as NumCast>::from(*c).unwrap())
+}
+
+/// Horner evaluation of the reversed polynomial, giving x^-n p(x) for coefficients of p given in ascending powers of x up to x^n
+pub(crate) fn reversed_horner<TNum, TCoefficient>(coefficients : &[TCoefficient], x_inverse : TNum) -> TNum
+    where TNum : ComplexFloat,
+          TCoefficient : Copy + NumCast {
+    coefficients.iter()
+        .fold(TNum::zero(), |accumulated, c| accumulated * x_inverse + <TNum as NumCast>::from(*c).unwrap())
+}
+
+fn estrin<TNum, TCoefficient>(coefficients : &[TCoefficient], x : TNum) -> TNum
+    where TNum : ComplexFloat,
+          TCoefficient : Copy + NumCast {
+    if coefficients.len() <= 4 {
+        return horner(coefficients, x);
+    }
+
+    let split = coefficients.len().next_power_of_two() / 2;
+    estrin(&coefficients[..split], x) + x.powi(split as i32) * estrin(&coefficients[split..], x)
+}
+
+fn aberth_roots(coefficients : &[Complex64]) -> Vec<Complex64> {
+    let mut coefficients = coefficients.to_vec();
+    while coefficients.last().is_some_and(|c| c.is_zero()) {
+        coefficients.pop();
+    }
+
+    // Roots at zero are removed up front, both because they are known exactly and because the initial radius below assumes a non-zero constant term
+    let zero_roots = coefficients.iter().position(|c| !c.is_zero()).unwrap_or(0);
+    let coefficients = coefficients.split_off(zero_roots);
+    let mut roots = vec![Complex64::zero(); zero_roots];
+
+    if coefficients.len() <= 1 {
+        return roots;
+    }
+
+    let degree = coefficients.len() - 1;
+    let leading = coefficients[degree];
+    let derivative_coefficients = coefficients.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(power, c)| c * power as f64)
+        .collect::<Vec<Complex64>>();
+
+    // Start on a circle whose radius is the geometric mean of the root moduli, offset from the real axis so conjugate pairs are not started on top of one another
+    let radius = (coefficients[0] / leading).norm().powf(1.0 / degree as f64);
+    let initial_estimates = (0..degree)
+        .map(|k| Complex64::from_polar(radius, TAU * k as f64 / degree as f64 + 0.4))
+        .collect::<Vec<Complex64>>();
+
+    let mut estimates = aberth_iteration(initial_estimates, |z| horner(&coefficients, z) / horner(&derivative_coefficients, z));
+
+    roots.append(&mut estimates);
+    roots
+}
+
+/// Simultaneously refines estimates of all the roots of a polynomial-like function, given the Newton correction p(z) / p'(z)
+pub(crate) fn aberth_iteration<TFunc>(mut estimates : Vec<Complex64>, newton_ratio : TFunc) -> Vec<Complex64>
+    where TFunc : Fn(Complex64) -> Complex64 {
+    let degree = estimates.len();
+    for _ in 0..ROOT_FINDING_MAX_ITERATIONS {
+        let mut largest_relative_step : f64 = 0.0;
+        for k in 0..degree {
+            let correction = newton_ratio(estimates[k]);
+            if correction.is_zero() {
+                continue;
+            }
+
+            let repulsion = (0..degree)
+                .filter(|j| *j != k)
+                .map(|j| (estimates[k] - estimates[j]).inv())
+                .sum::<Complex64>();
+            let step = correction / (1.0 - correction * repulsion);
+            if !step.is_finite() {
+                continue;
+            }
+
+            estimates[k] -= step;
+            largest_relative_step = largest_relative_step.max(step.norm() / estimates[k].norm().max(f64::MIN_POSITIVE));
+        }
+
+        if largest_relative_step < 4.0 * f64::EPSILON {
+            break;
+        }
+    }
+
+    estimates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polynomial;
+    use num::complex::Complex64;
+
+    const CUBIC_COEFFICIENTS : [f64; 4] = [-6.0, 11.0, -6.0, 1.0];
+    const CUBIC : Polynomial<f64> = Polynomial::from_static(&CUBIC_COEFFICIENTS);
+
+    #[test]
+    fn horner_and_estrin_agree() {
+        let high_degree = Polynomial::new((0..23).map(|i| 1.0 / (i as f64 + 1.0)).collect());
+        let x = Complex64::new(0.7, -0.3);
+        assert!((high_degree.evaluate(x) - high_degree.evaluate_estrin(x)).norm() < 1e-14);
+        assert_eq!(CUBIC.evaluate(4.0), 6.0);
+    }
+
+    #[test]
+    fn cubic_roots() {
+        let roots = CUBIC.real_roots();
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((root - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn complex_roots_with_zero_root() {
+        // x^3 + x = x (x - i) (x + i)
+        let roots = Polynomial::new(vec![0.0, 1.0, 0.0, 1.0]).roots();
+        assert_eq!(roots.len(), 3);
+        for expected in [Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(0.0, -1.0)] {
+            assert!(roots.iter().any(|root| (root - expected).norm() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn arithmetic_and_derivative() {
+        let linear = Polynomial::new(vec![-1.0, 1.0]);
+        let product = &CUBIC * &linear;
+        assert_eq!(product.degree(), 4);
+        assert_eq!(product.evaluate(1.5), CUBIC.evaluate(1.5) * 0.5);
+
+        let (quotient, remainder) = product.div_rem(&linear).unwrap();
+        assert_eq!(quotient.coefficients(), CUBIC.coefficients());
+        assert!(remainder.coefficients().iter().all(|c| c.abs() < 1e-14));
+
+        assert_eq!(CUBIC.derivative().coefficients(), &[11.0, -12.0, 3.0]);
+        assert_eq!(CUBIC.derivative().integral().evaluate(2.0), CUBIC.evaluate(2.0) - CUBIC.evaluate(0.0));
+        assert_eq!((&CUBIC - &CUBIC).degree(), 0);
+    }
+
+    #[test]
+    fn division_by_zero_and_of_empty_polynomials() {
+        let linear = Polynomial::new(vec![-1.0, 1.0]);
+        assert!(CUBIC.div_rem(&Polynomial::new(vec![0.0, 0.0])).is_none());
+        assert!(CUBIC.div_rem(&Polynomial::new(vec![])).is_none());
+
+        let (quotient, remainder) = Polynomial::new(vec![]).div_rem(&linear).unwrap();
+        assert_eq!(quotient.coefficients(), &[0.0]);
+        assert_eq!(remainder.coefficients(), &[0.0]);
+
+        let (quotient, remainder) = Polynomial::new(vec![]).div_rem(&Polynomial::new(vec![2.0])).unwrap();
+        assert_eq!(quotient.coefficients(), &[0.0]);
+        assert_eq!(remainder.coefficients(), &[0.0]);
+    }
+}
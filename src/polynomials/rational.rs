@@ -0,0 +1,185 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::complex::{Complex64, ComplexFloat};
+use num::{Num, NumCast, One};
+
+use super::polynomial::{horner, reversed_horner, Polynomial};
+
+/// A ratio of two polynomials, numerator / denominator
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rational<T : Clone + 'static> {
+    pub numerator : Polynomial<T>,
+    pub denominator : Polynomial<T>,
+}
+
+impl<T : Clone + 'static> Rational<T> {
+    /// Wraps numerator and denominator coefficient tables, given in ascending powers, without allocating
+    pub const fn from_static(numerator : &'static [T], denominator : &'static [T]) -> Rational<T> {
+        Rational {
+            numerator : Polynomial::from_static(numerator),
+            denominator : Polynomial::from_static(denominator),
+        }
+    }
+
+    pub fn new(numerator : Polynomial<T>, denominator : Polynomial<T>) -> Rational<T> {
+        Rational { numerator, denominator }
+    }
+}
+
+impl<T> Rational<T>
+    where T : Copy + NumCast + 'static {
+    /// Evaluates the ratio. Outside the unit disk both polynomials are evaluated in 1/x, so that the largest powers of x neither overflow nor dominate the rounding error.
+    pub fn evaluate<TNum : ComplexFloat>(&self, x : TNum) -> TNum {
+        let numerator = self.numerator.coefficients();
+        let denominator = self.denominator.coefficients();
+
+        if x.abs() <= TNum::Real::one() {
+            return horner(numerator, x) / horner(denominator, x);
+        }
+
+        let x_inverse = x.recip();
+        let ratio = reversed_horner(numerator, x_inverse) / reversed_horner(denominator, x_inverse);
+        let power_difference = numerator.len() as i32 - denominator.len() as i32;
+        if power_difference == 0 { ratio } else { ratio * x.powi(power_difference) }
+    }
+
+    /// The zeros of the numerator
+    pub fn roots(&self) -> Vec<Complex64> {
+        self.numerator.roots()
+    }
+
+    /// The zeros of the denominator
+    pub fn poles(&self) -> Vec<Complex64> {
+        self.denominator.roots()
+    }
+}
+
+impl<T> Rational<T>
+    where T : Num + Copy + NumCast + 'static {
+    /// Derivative by the quotient rule, (p'q - pq') / q^2
+    pub fn derivative(&self) -> Rational<T> {
+        Rational::new(
+            &(&self.numerator.derivative() * &self.denominator) - &(&self.numerator * &self.denominator.derivative()),
+            &self.denominator * &self.denominator,
+        )
+    }
+
+    pub fn recip(&self) -> Rational<T> {
+        Rational::new(self.denominator.clone(), self.numerator.clone())
+    }
+}
+
+impl<T> From<Polynomial<T>> for Rational<T>
+    where T : Num + Copy + 'static {
+    fn from(value : Polynomial<T>) -> Self {
+        Rational::new(value, Polynomial::new(vec![T::one()]))
+    }
+}
+
+impl<T> Add for &Rational<T>
+    where T : Num + Copy + 'static {
+    type Output = Rational<T>;
+
+    fn add(self, rhs : Self) -> Rational<T> {
+        Rational::new(
+            &(&self.numerator * &rhs.denominator) + &(&rhs.numerator * &self.denominator),
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<T> Sub for &Rational<T>
+    where T : Num + Copy + 'static {
+    type Output = Rational<T>;
+
+    fn sub(self, rhs : Self) -> Rational<T> {
+        Rational::new(
+            &(&self.numerator * &rhs.denominator) - &(&rhs.numerator * &self.denominator),
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<T> Mul for &Rational<T>
+    where T : Num + Copy + 'static {
+    type Output = Rational<T>;
+
+    fn mul(self, rhs : Self) -> Rational<T> {
+        Rational::new(&self.numerator * &rhs.numerator, &self.denominator * &rhs.denominator)
+    }
+}
+
+impl<T> Div for &Rational<T>
+    where T : Num + Copy + 'static {
+    type Output = Rational<T>;
+
+    fn div(self, rhs : Self) -> Rational<T> {
+        Rational::new(&self.numerator * &rhs.denominator, &self.denominator * &rhs.numerator)
+    }
+}
+
+impl<T> Neg for &Rational<T>
+    where T : Num + Copy + Neg<Output = T> + 'static {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Rational<T> {
+        Rational::new(-&self.numerator, self.denominator.clone())
+    }
+}
+
+macro_rules! forward_owned_binary_operation {
+    ($trait:ident, $method:ident) => {
+        impl<T> $trait for Rational<T>
+            where T : Num + Copy + 'static {
+            type Output = Rational<T>;
+
+            fn $method(self, rhs : Self) -> Rational<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_owned_binary_operation!(Add, add);
+forward_owned_binary_operation!(Sub, sub);
+forward_owned_binary_operation!(Mul, mul);
+forward_owned_binary_operation!(Div, div);
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+    use crate::polynomials::Polynomial;
+    use num::complex::Complex64;
+
+    // (1 + x) / (1 - x + x^2)
+    const RATIO : Rational<f64> = Rational::from_static(&[1.0, 1.0], &[1.0, -1.0, 1.0]);
+
+    fn exact(x : f64) -> f64 {
+        (1.0 + x) / (1.0 - x + x * x)
+    }
+
+    #[test]
+    fn evaluates_inside_and_outside_unit_disk() {
+        for x in [-0.5, 0.3, 2.0, -40.0, 1e100] {
+            assert!((RATIO.evaluate(x) - exact(x)).abs() <= 1e-15 * exact(x).abs(), "{x}");
+        }
+    }
+
+    #[test]
+    fn derivative_matches_finite_difference() {
+        let h = 1e-6;
+        let finite_difference = (exact(0.4 + h) - exact(0.4 - h)) / (2.0 * h);
+        assert!((RATIO.derivative().evaluate(0.4) - finite_difference).abs() < 1e-8);
+    }
+
+    #[test]
+    fn arithmetic_and_poles() {
+        let sum = &RATIO + &Rational::from(Polynomial::new(vec![0.0, 2.0]));
+        assert!((sum.evaluate(0.25) - exact(0.25) - 0.5).abs() < 1e-15);
+        assert!(((&RATIO / &RATIO).evaluate(Complex64::new(0.3, 2.0)) - 1.0).norm() < 1e-14);
+
+        let poles = RATIO.poles();
+        assert!(poles.iter().all(|pole| (pole - Complex64::new(0.5, f64::sqrt(0.75))).norm() < 1e-12 || (pole - Complex64::new(0.5, -(f64::sqrt(0.75)))).norm() < 1e-12));
+        assert!((RATIO.roots()[0] + 1.0).norm() < 1e-12);
+    }
+}
@@ -0,0 +1,283 @@
+use std::f64::consts::PI;
+
+use crate::calculators::solve_linear_system;
+use super::{Polynomial, Rational};
+
+const REMEZ_MAX_ITERATIONS : usize = 60;
+const LEVELLED_ERROR_TOLERANCE : f64 = 1e-6;
+const GOLDEN_SECTION_STEPS : usize = 40;
+
+/// Whether an approximation is judged by its absolute error, or by its error relative to the function being approximated
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorMeasure {
+    Absolute,
+    Relative,
+}
+
+/// A generated rational approximation, together with the largest error it makes on the interval it was judged over
+#[derive(Clone, Debug)]
+pub struct RationalApproximation {
+    pub approximation : Rational<f64>,
+    pub max_error : f64,
+}
+
+impl RationalApproximation {
+    /// Measures the largest error of an approximation to `function` on [lower_limit, upper_limit] by dense sampling followed by local refinement
+    pub fn measure<TFunc>(approximation : Rational<f64>, function : TFunc, lower_limit : f64, upper_limit : f64, error_measure : ErrorMeasure) -> RationalApproximation
+        where TFunc : Fn(f64) -> f64 {
+        let error = |x : f64| weighted_error(&approximation, &function, error_measure, x);
+        let grid = sampling_grid(lower_limit, upper_limit, 2000);
+        let max_error = alternating_extrema(&grid, &error).iter()
+            .map(|(_, e)| e.abs())
+            .fold(0.0, f64::max);
+
+        RationalApproximation { approximation, max_error }
+    }
+
+    pub fn numerator(&self) -> &[f64] {
+        self.approximation.numerator.coefficients()
+    }
+
+    pub fn denominator(&self) -> &[f64] {
+        self.approximation.denominator.coefficients()
+    }
+
+    /// Rust source declaring the coefficients as constant tables, in the layout used by the tables in `special_functions`
+    pub fn constant_tables(&self, name : &str) -> String {
+        let table = |suffix : &str, coefficients : &[f64]| {
+            let entries = coefficients.iter().map(|c| format!("    {c:.20E},\n")).collect::<String>();
+            format!("const {name}_{suffix} : [f64; {}] = [\n{entries}];\n", coefficients.len())
+        };
+
+        format!("// Maximum error {:.3E}\n{}\n{}", self.max_error, table("NUMERATOR", self.numerator()), table("DENOMINATOR", self.denominator()))
+    }
+}
+
+/// The Padé approximant with the given numerator and denominator degrees, built from the Taylor coefficients c_0, c_1, ... of a function about zero.
+/// At least numerator_degree + denominator_degree + 1 coefficients are needed. Returns None when the approximant does not exist.
+pub fn pade(taylor_coefficients : &[f64], numerator_degree : usize, denominator_degree : usize) -> Option<Rational<f64>> {
+    if taylor_coefficients.len() < numerator_degree + denominator_degree + 1 {
+        return None;
+    }
+
+    let taylor = |i : isize| if i < 0 { 0.0 } else { taylor_coefficients[i as usize] };
+    let m = numerator_degree as isize;
+
+    // The denominator, normalised so q_0 = 1, makes the coefficients of x^(m+1) ... x^(m+n) in q(x) f(x) vanish
+    let matrix = (1..=denominator_degree as isize)
+        .map(|k| (1..=denominator_degree as isize).map(|j| taylor(m + k - j)).collect())
+        .collect();
+    let rhs = (1..=denominator_degree as isize).map(|k| -taylor(m + k)).collect();
+    let mut denominator = vec![1.0];
+    denominator.extend(solve_linear_system(matrix, rhs)?);
+
+    let numerator = (0..=numerator_degree)
+        .map(|k| (0..=k.min(denominator_degree)).map(|j| denominator[j] * taylor_coefficients[k - j]).sum())
+        .collect();
+
+    Some(Rational::new(Polynomial::new(numerator), Polynomial::new(denominator)))
+}
+
+/// The best rational approximation, in the minimax sense, to `function` on [lower_limit, upper_limit] with the given degrees, found by the Remez exchange algorithm.
+/// The denominator is normalised to have a constant term of one. For `ErrorMeasure::Relative` the function must not vanish on the interval.
+/// Returns None when the equations for the initial reference are singular, as they can be for odd or even functions whose best approximation has lower degrees.
+pub fn minimax<TFunc>(function : TFunc, lower_limit : f64, upper_limit : f64, numerator_degree : usize, denominator_degree : usize, error_measure : ErrorMeasure) -> Option<RationalApproximation>
+    where TFunc : Fn(f64) -> f64 {
+    let reference_size = numerator_degree + denominator_degree + 2;
+    let grid = sampling_grid(lower_limit, upper_limit, (100 * reference_size).max(2000));
+
+    let mut reference = sampling_grid(lower_limit, upper_limit, reference_size);
+    let mut best : Option<RationalApproximation> = None;
+
+    for _ in 0..REMEZ_MAX_ITERATIONS {
+        let Some((approximation, levelled_error)) = solve_reference(&function, &reference, numerator_degree, denominator_degree, error_measure) else {
+            break;
+        };
+
+        let error = |x : f64| weighted_error(&approximation, &function, error_measure, x);
+        let extrema = alternating_extrema(&grid, &error);
+        let max_error = extrema.iter().map(|(_, e)| e.abs()).fold(0.0, f64::max);
+
+        if best.as_ref().is_none_or(|b| max_error < b.max_error) {
+            best = Some(RationalApproximation { approximation, max_error });
+        }
+
+        if max_error - levelled_error.abs() <= LEVELLED_ERROR_TOLERANCE * max_error || extrema.len() < reference_size {
+            break;
+        }
+
+        reference = exchange(extrema, reference_size);
+    }
+
+    best
+}
+
+fn weighted_error<TFunc>(approximation : &Rational<f64>, function : &TFunc, error_measure : ErrorMeasure, x : f64) -> f64
+    where TFunc : Fn(f64) -> f64 {
+    let value = function(x);
+    let difference = value - approximation.evaluate(x);
+    match error_measure {
+        ErrorMeasure::Absolute => difference,
+        ErrorMeasure::Relative => difference / value.abs(),
+    }
+}
+
+/// Points clustered towards the ends of the interval like Chebyshev points, which is where the error of polynomial-like approximations oscillates fastest
+fn sampling_grid(lower_limit : f64, upper_limit : f64, size : usize) -> Vec<f64> {
+    (0..size)
+        .map(|i| 0.5 * (lower_limit + upper_limit) - 0.5 * (upper_limit - lower_limit) * (PI * i as f64 / (size - 1) as f64).cos())
+        .collect()
+}
+
+/// Solves for the approximation whose weighted error equioscillates with magnitude E on the reference points.
+/// The product of E with the denominator makes this nonlinear, so that product is taken from the previous estimate of E until E settles.
+fn solve_reference<TFunc>(function : &TFunc, reference : &[f64], numerator_degree : usize, denominator_degree : usize, error_measure : ErrorMeasure) -> Option<(Rational<f64>, f64)>
+    where TFunc : Fn(f64) -> f64 {
+    let values = reference.iter().map(|x| function(*x)).collect::<Vec<f64>>();
+    let weights = values.iter().map(|v| match error_measure { ErrorMeasure::Absolute => 1.0, ErrorMeasure::Relative => v.abs() }).collect::<Vec<f64>>();
+
+    let mut levelled_error = 0.0;
+    let mut solution = vec![];
+    for _ in 0..20 {
+        let matrix = reference.iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let signed_weight = if i % 2 == 0 { weights[i] } else { -weights[i] };
+                (0..=numerator_degree).map(|k| x.powi(k as i32))
+                    .chain((1..=denominator_degree).map(|j| -x.powi(j as i32) * (values[i] - signed_weight * levelled_error)))
+                    .chain(std::iter::once(signed_weight))
+                    .collect()
+            })
+            .collect();
+
+        solution = solve_linear_system(matrix, values.clone())?;
+        let new_levelled_error = solution[numerator_degree + denominator_degree + 1];
+        let settled = (new_levelled_error - levelled_error).abs() <= 1e-12 * new_levelled_error.abs();
+        levelled_error = new_levelled_error;
+        if settled {
+            break;
+        }
+    }
+
+    let numerator = solution[..=numerator_degree].to_vec();
+    let denominator = std::iter::once(1.0).chain(solution[numerator_degree + 1..=numerator_degree + denominator_degree].iter().copied()).collect();
+    Some((Rational::new(Polynomial::new(numerator), Polynomial::new(denominator)), levelled_error))
+}
+
+/// The extremum of the error in each run of samples over which it keeps the same sign, refined by golden-section search between the neighbouring samples
+fn alternating_extrema<TFunc>(grid : &[f64], error : &TFunc) -> Vec<(f64, f64)>
+    where TFunc : Fn(f64) -> f64 {
+    let errors = grid.iter().map(|x| error(*x)).collect::<Vec<f64>>();
+
+    let mut runs : Vec<usize> = vec![];
+    for i in 0..grid.len() {
+        match runs.last_mut() {
+            Some(last) if (errors[*last] >= 0.0) == (errors[i] >= 0.0) => {
+                if errors[i].abs() > errors[*last].abs() {
+                    *last = i;
+                }
+            },
+            _ => runs.push(i),
+        }
+    }
+
+    runs.into_iter()
+        .map(|i| {
+            let lower = grid[i.saturating_sub(1)];
+            let upper = grid[(i + 1).min(grid.len() - 1)];
+            let x = golden_section_maximum(|x| error(x).abs(), lower, upper);
+            if error(x).abs() > errors[i].abs() { (x, error(x)) } else { (grid[i], errors[i]) }
+        })
+        .collect()
+}
+
+fn golden_section_maximum<TFunc>(function : TFunc, mut lower : f64, mut upper : f64) -> f64
+    where TFunc : Fn(f64) -> f64 {
+    let inverse_golden_ratio = 0.5 * (5.0f64.sqrt() - 1.0);
+    let mut left = upper - inverse_golden_ratio * (upper - lower);
+    let mut right = lower + inverse_golden_ratio * (upper - lower);
+    let (mut left_value, mut right_value) = (function(left), function(right));
+    for _ in 0..GOLDEN_SECTION_STEPS {
+        if left_value > right_value {
+            upper = right;
+            right = left;
+            right_value = left_value;
+            left = upper - inverse_golden_ratio * (upper - lower);
+            left_value = function(left);
+        }
+        else {
+            lower = left;
+            left = right;
+            left_value = right_value;
+            right = lower + inverse_golden_ratio * (upper - lower);
+            right_value = function(right);
+        }
+    }
+
+    0.5 * (lower + upper)
+}
+
+/// Reduces a set of alternating extrema to a new reference of the required size, discarding the smallest extrema while keeping the signs alternating
+fn exchange(mut extrema : Vec<(f64, f64)>, reference_size : usize) -> Vec<f64> {
+    while extrema.len() > reference_size {
+        let last = extrema.len() - 1;
+        if extrema.len() == reference_size + 1 {
+            extrema.remove(if extrema[0].1.abs() < extrema[last].1.abs() { 0 } else { last });
+            continue;
+        }
+
+        let smallest = (0..extrema.len()).min_by(|a, b| extrema[*a].1.abs().total_cmp(&extrema[*b].1.abs())).unwrap();
+        if smallest == 0 || smallest == last {
+            extrema.remove(smallest);
+            continue;
+        }
+
+        // Removing an interior extremum leaves its neighbours with the same sign, so the smaller of them goes too
+        let smaller_neighbour = if extrema[smallest - 1].1.abs() < extrema[smallest + 1].1.abs() { smallest - 1 } else { smallest + 1 };
+        extrema.remove(smallest.max(smaller_neighbour));
+        extrema.remove(smallest.min(smaller_neighbour));
+    }
+
+    extrema.into_iter().map(|(x, _)| x).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minimax, pade, ErrorMeasure, RationalApproximation};
+
+    #[test]
+    fn polynomial_minimax_of_exponential() {
+        // The best degree n polynomial approximation to exp on [-1, 1] has error close to 1 / (2^n (n + 1)!)
+        let approximation = minimax(f64::exp, -1.0, 1.0, 6, 0, ErrorMeasure::Absolute).unwrap();
+        let expected = 1.0 / (64.0 * 5040.0);
+        assert!((approximation.max_error / expected - 1.0).abs() < 0.05, "{}", approximation.max_error);
+        assert_eq!(approximation.denominator(), &[1.0]);
+    }
+
+    #[test]
+    fn rational_minimax_beats_pade() {
+        let taylor = (0..9).scan(1.0, |factorial, n| { if n > 0 { *factorial *= n as f64 }; Some(1.0 / *factorial) }).collect::<Vec<f64>>();
+        let pade_approximation = RationalApproximation::measure(pade(&taylor, 4, 4).unwrap(), f64::exp, -1.0, 1.0, ErrorMeasure::Relative);
+        let minimax_approximation = minimax(f64::exp, -1.0, 1.0, 4, 4, ErrorMeasure::Relative).unwrap();
+
+        // The Padé approximant is most accurate at zero, and its error at the ends of the interval is about 4.05e-8
+        assert!((pade_approximation.max_error / 4.0532e-8 - 1.0).abs() < 1e-3, "{}", pade_approximation.max_error);
+        assert!(minimax_approximation.max_error < pade_approximation.max_error / 100.0, "{}", minimax_approximation.max_error);
+        assert!((minimax_approximation.approximation.evaluate(0.5) / 0.5f64.exp() - 1.0).abs() <= minimax_approximation.max_error * 1.0001);
+    }
+
+    #[test]
+    fn singular_reference_is_reported() {
+        // sin is odd, so on the symmetric initial reference the equations for a constant over a linear denominator are singular
+        assert!(minimax(f64::sin, -1.0, 1.0, 0, 1, ErrorMeasure::Absolute).is_none());
+        assert!(minimax(f64::sin, 0.0, 1.0, 0, 1, ErrorMeasure::Absolute).is_some());
+    }
+
+    #[test]
+    fn pade_of_exponential_is_known() {
+        // The [1/1] Padé approximant of exp is (1 + x/2) / (1 - x/2)
+        let approximant = pade(&[1.0, 1.0, 0.5], 1, 1).unwrap();
+        assert_eq!(approximant.numerator.coefficients(), &[1.0, 0.5]);
+        assert_eq!(approximant.denominator.coefficients(), &[1.0, -0.5]);
+    }
+}
@@ -0,0 +1,226 @@
+use std::f64::consts::PI;
+
+use num::complex::Complex64;
+
+use crate::asymptotic_series;
+use super::{iv, jv, kv};
+
+// Ai(0) and -Ai'(0) (DLMF 9.2.3, 9.2.4)
+const AI_ZERO : f64 = 0.3550280538878172;
+const MINUS_AI_PRIME_ZERO : f64 = 0.2588194037928068;
+
+const SERIES_RADIUS : f64 = 2.0;
+// Past this the smallest term of the asymptotic expansion, about e^(-2|ζ|), is below machine precision
+const ASYMPTOTIC_RADIUS : f64 = 9.0;
+const ASYMPTOTIC_MAX_TERMS : i32 = 100;
+
+/// The Airy function Ai (DLMF 9.2.2)
+pub fn airy_ai(argument : Complex64) -> Complex64 {
+    ai_and_derivative(argument).0
+}
+
+/// The derivative of the Airy function Ai
+pub fn airy_ai_prime(argument : Complex64) -> Complex64 {
+    ai_and_derivative(argument).1
+}
+
+/// The Airy function Bi (DLMF 9.2.2)
+pub fn airy_bi(argument : Complex64) -> Complex64 {
+    bi_and_derivative(argument).0
+}
+
+/// The derivative of the Airy function Bi
+pub fn airy_bi_prime(argument : Complex64) -> Complex64 {
+    bi_and_derivative(argument).1
+}
+
+fn ai_and_derivative(argument : Complex64) -> (Complex64, Complex64) {
+    if argument.norm() <= SERIES_RADIUS {
+        let (f, f_prime, g, g_prime) = maclaurin_series(argument);
+        return (AI_ZERO * f - MINUS_AI_PRIME_ZERO * g, AI_ZERO * f_prime - MINUS_AI_PRIME_ZERO * g_prime);
+    }
+
+    if argument.norm() >= ASYMPTOTIC_RADIUS {
+        // Beyond |ph z| = 2π/3 the expansion loses its dominant exponential, so rotate back into that sector (DLMF 9.2.12)
+        if argument.arg().abs() > 2.0 * PI / 3.0 {
+            let omega = Complex64::from_polar(1.0, 2.0 * PI / 3.0);
+            let (ai_omega, ai_prime_omega) = ai_and_derivative(omega * argument);
+            let (ai_omega_squared, ai_prime_omega_squared) = ai_and_derivative(omega * omega * argument);
+            return (
+                -omega * ai_omega - omega * omega * ai_omega_squared,
+                -omega * omega * ai_prime_omega - omega * ai_prime_omega_squared,
+            );
+        }
+
+        if let Some(values) = ai_asymptotic_expansion(argument) {
+            return values;
+        }
+    }
+
+    // DLMF 9.6.1, 9.6.2, 9.6.6 and 9.6.7, keeping ζ off the branch cut of the Bessel functions
+    if argument.re >= 0.0 {
+        let zeta = 2.0 / 3.0 * argument * argument.sqrt();
+        (
+            (argument / 3.0).sqrt() * kv(zeta, 1.0 / 3.0) / PI,
+            -argument * kv(zeta, 2.0 / 3.0) / (PI * f64::sqrt(3.0)),
+        )
+    }
+    else {
+        let reflected = -argument;
+        let zeta = 2.0 / 3.0 * reflected * reflected.sqrt();
+        (
+            reflected.sqrt() / 3.0 * (jv(zeta, 1.0 / 3.0) + jv(zeta, -1.0 / 3.0)),
+            reflected / 3.0 * (jv(zeta, 2.0 / 3.0) - jv(zeta, -2.0 / 3.0)),
+        )
+    }
+}
+
+fn bi_and_derivative(argument : Complex64) -> (Complex64, Complex64) {
+    if argument.norm() <= SERIES_RADIUS {
+        let (f, f_prime, g, g_prime) = maclaurin_series(argument);
+        let root_three = f64::sqrt(3.0);
+        return (
+            root_three * (AI_ZERO * f + MINUS_AI_PRIME_ZERO * g),
+            root_three * (AI_ZERO * f_prime + MINUS_AI_PRIME_ZERO * g_prime),
+        );
+    }
+
+    if argument.norm() >= ASYMPTOTIC_RADIUS {
+        // DLMF 9.2.11 and its derivative
+        let omega = Complex64::from_polar(1.0, 2.0 * PI / 3.0);
+        let (ai_plus, ai_prime_plus) = ai_and_derivative(omega * argument);
+        let (ai_minus, ai_prime_minus) = ai_and_derivative(omega.conj() * argument);
+        let phase = Complex64::from_polar(1.0, PI / 6.0);
+        return (
+            phase * ai_plus + phase.conj() * ai_minus,
+            phase * omega * ai_prime_plus + (phase * omega).conj() * ai_prime_minus,
+        );
+    }
+
+    // DLMF 9.6.3, 9.6.4, 9.6.8 and 9.6.9
+    if argument.re >= 0.0 {
+        let zeta = 2.0 / 3.0 * argument * argument.sqrt();
+        (
+            (argument / 3.0).sqrt() * (iv(zeta, -1.0 / 3.0) + iv(zeta, 1.0 / 3.0)),
+            argument / f64::sqrt(3.0) * (iv(zeta, -2.0 / 3.0) + iv(zeta, 2.0 / 3.0)),
+        )
+    }
+    else {
+        let reflected = -argument;
+        let zeta = 2.0 / 3.0 * reflected * reflected.sqrt();
+        (
+            (reflected / 3.0).sqrt() * (jv(zeta, -1.0 / 3.0) - jv(zeta, 1.0 / 3.0)),
+            reflected / f64::sqrt(3.0) * (jv(zeta, -2.0 / 3.0) + jv(zeta, 2.0 / 3.0)),
+        )
+    }
+}
+
+// The series f, f', g and g' of DLMF 9.4.1 to 9.4.4, summed until the terms are negligible
+fn maclaurin_series(argument : Complex64) -> (Complex64, Complex64, Complex64, Complex64) {
+    let cube = argument * argument * argument;
+    let mut f_term = Complex64::new(1.0, 0.0);
+    let mut f_prime_term = argument * argument / 2.0;
+    let mut g_term = argument;
+    let mut g_prime_term = Complex64::new(1.0, 0.0);
+    let (mut f, mut f_prime, mut g, mut g_prime) = (f_term, f_prime_term, g_term, g_prime_term);
+
+    let negligible = |term : Complex64, sum : Complex64| term.norm() <= f64::EPSILON * sum.norm();
+    let mut k = 1.0;
+    while !(negligible(f_term, f) && negligible(f_prime_term, f_prime) && negligible(g_term, g) && negligible(g_prime_term, g_prime)) {
+        f_term *= cube / ((3.0 * k) * (3.0 * k - 1.0));
+        g_term *= cube / ((3.0 * k + 1.0) * (3.0 * k));
+        f_prime_term *= cube / ((3.0 * k + 2.0) * (3.0 * k));
+        g_prime_term *= cube / ((3.0 * k) * (3.0 * k - 2.0));
+        f += f_term;
+        g += g_term;
+        f_prime += f_prime_term;
+        g_prime += g_prime_term;
+        k += 1.0;
+    }
+
+    (f, f_prime, g, g_prime)
+}
+
+// DLMF 9.7.5 and 9.7.6 for |ph z| <= 2π/3, truncated at their smallest terms; None if the truncation error is not negligible
+fn ai_asymptotic_expansion(argument : Complex64) -> Option<(Complex64, Complex64)> {
+    let zeta = 2.0 / 3.0 * argument * argument.sqrt();
+
+    // u_k and v_k from DLMF 9.7.2, with the alternating sign and powers of ζ folded in
+    let next_u = |u : Complex64, k : f64| -u * (6.0 * k - 5.0) * (6.0 * k - 3.0) * (6.0 * k - 1.0) / ((2.0 * k - 1.0) * 216.0 * k * zeta);
+    let mut u = Complex64::new(1.0, 0.0);
+    let ai_series = asymptotic_series(f64::EPSILON, 0, ASYMPTOTIC_MAX_TERMS, |k| {
+        if k > 0 {
+            u = next_u(u, k as f64);
+        }
+        u
+    });
+    let mut u = Complex64::new(1.0, 0.0);
+    let ai_prime_series = asymptotic_series(f64::EPSILON, 0, ASYMPTOTIC_MAX_TERMS, |k| {
+        if k == 0 {
+            return u;
+        }
+        let k = k as f64;
+        u = next_u(u, k);
+        -(6.0 * k + 1.0) / (6.0 * k - 1.0) * u
+    });
+
+    if ai_series.error > 4.0 * f64::EPSILON * ai_series.value.norm() || ai_prime_series.error > 4.0 * f64::EPSILON * ai_prime_series.value.norm() {
+        return None;
+    }
+
+    let quarter_power = argument.powf(0.25);
+    let exponential = (-zeta).exp() / (2.0 * PI.sqrt());
+    Some((exponential / quarter_power * ai_series.value, -exponential * quarter_power * ai_prime_series.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::complex::Complex64;
+
+    use super::{airy_ai, airy_ai_prime, airy_bi, airy_bi_prime};
+
+    #[test]
+    fn matches_reference_values() {
+        // From mpmath, as Ai, Ai', Bi, Bi'
+        let references = [
+            ((0.0, 0.0), [(0.3550280538878172, 0.0), (-0.2588194037928068, 0.0), (0.6149266274460007, 0.0), (0.4482883573538264, 0.0)]),
+            ((0.5, 0.0), [(0.2316936064808335, 0.0), (-0.2249105326646839, 0.0), (0.8542770431031555, 0.0), (0.5445725641405923, 0.0)]),
+            ((1.5, -1.0), [(0.017048920054579496, 0.08326920467824043), (-0.051676961170336634, -0.1101433886645319), (0.7635143010350922, -1.2190757998193475), (0.09801981538105, -1.7162349060639508)]),
+            ((-1.8, 0.0), [(0.3407615591242139, 0.0), (0.5099976277197067, 0.0), (-0.341405831830135, 0.0), (0.4231513737386893, 0.0)]),
+            ((3.0, 0.0), [(0.006591139357460719, 0.0), (-0.011912976705951319, 0.0), (14.037328963730232, 0.0), (22.92221496638217, 0.0)]),
+            ((-4.0, 2.0), [(-5.808180520482092, -9.454268205550928), (-16.742869716891654, 15.902468347905534), (9.459084443341435, -5.803408955151473), (-15.909847641259887, -16.730289969079323)]),
+            ((5.0, 0.0), [(0.00010834442813607442, 0.0), (-0.0002474138908684625, 0.0), (657.7920441711712, 0.0), (1435.8190802179824, 0.0)]),
+            ((-6.0, 0.0), [(-0.3291451736298231, 0.0), (0.3459354872813429, 0.0), (-0.14669837667055705, 0.0), (-0.812898785105067, 0.0)]),
+            ((2.0, 5.0), [(-0.5949625456885304, -0.9174528389615631), (0.00022112103890024984, 2.529661288576281), (0.918670240867993, -0.5323100120341309), (-2.6115591652129186, 0.12131104792357034)]),
+            ((7.5, 0.0), [(1.9172560675134309e-07, 0.0), (-5.312713959720545e-7, 0.0), (303229.6151125334, 0.0), (819987.8353587996, 0.0)]),
+            ((10.0, 0.0), [(1.1047532552898686e-10, 0.0), (-3.5206336767389237e-10, 0.0), (455641153.54822516, 0.0), (1429236134.4828658, 0.0)]),
+            ((-10.0, 0.0), [(0.04024123848644319, 0.0), (0.99626504413279, 0.0), (-0.3146798296438386, 0.0), (0.11941411339990925, 0.0)]),
+            ((-8.0, -8.0), [(-2221557713.227391, -702648577.6864733), (4996511121.782896, -5975361553.722936), (-702648577.6864733, 2221557713.227391), (-5975361553.722936, -4996511121.782896)]),
+            ((0.0, 12.0), [(20659441.47950501, -44627666.75747433), (-158985314.73690397, 59155301.22464071), (44627666.75747433, 20659441.47950501), (-59155301.22464071, -158985314.73690397)]),
+            ((-15.0, 3.0), [(15515.79805213813, -4571.311979784987), (-23507.95739858863, -58653.71223089736), (4571.311980730207, 15515.798049806806), (58653.712240358305, -23507.957395842204)]),
+            ((20.0, 0.0), [(1.6916728686705404e-27, 0.0), (-7.586391625748355e-27, 0.0), (2.103765049651104e25, 0.0), (9.381839336133964e25, 0.0)]),
+            ((-30.0, 0.0), [(-0.08796818845684216, 0.0), (1.228620602637485, 0.0), (-0.22444694220056632, 0.0), (-0.4836947258276815, 0.0)]),
+            ((6.0, -25.0), [(-605241745166967.7, -524578234166453.4), (4053193715206342.5, 195711429988295.5), (-524578234166453.4, 605241745166967.7), (195711429988295.5, -4053193715206342.5)]),
+        ];
+
+        for ((re, im), expected) in references {
+            let argument = Complex64::new(re, im);
+            let values = [airy_ai(argument), airy_ai_prime(argument), airy_bi(argument), airy_bi_prime(argument)];
+            for (value, (expected_re, expected_im)) in values.into_iter().zip(expected) {
+                let expected = Complex64::new(expected_re, expected_im);
+                println!("At {argument}: {value} against {expected}");
+                assert!((value - expected).norm() <= 1e-12 * expected.norm());
+            }
+        }
+    }
+
+    #[test]
+    fn satisfies_wronskian() {
+        // DLMF 9.2.7
+        for (re, im) in [(0.3, 0.1), (-2.5, 1.0), (4.0, -3.0), (-9.5, -0.5), (12.0, 7.0), (-20.0, 0.0)] {
+            let argument = Complex64::new(re, im);
+            let wronskian = airy_ai(argument) * airy_bi_prime(argument) - airy_ai_prime(argument) * airy_bi(argument);
+            assert!((wronskian - 1.0 / std::f64::consts::PI).norm() < 1e-12, "{wronskian} at {argument}");
+        }
+    }
+}
@@ -1,12 +1,25 @@
 use std::f64::consts::PI;
 
+use num::complex::Complex64;
+
+use crate::{asymptotic_series, with_error::WithError};
+use super::bessel_real_order::scaled_modified_bessel;
+
 extern crate libm;
 
 const PRECISION : f64 = 0.008;
 const PRECISION_EIGHTH_ROOT : f64 = 0.54687;
 
+// The large argument expansion is only trusted when its truncation error is below this, otherwise forward recurrence is used instead
+const LARGE_ARGUMENT_TOLERANCE : f64 = 1e-12;
+const LARGE_ARGUMENT_MAX_TERMS : i32 = 200;
+
 /// The Bessel function of the first kind (DLMF 10.2.2)
 pub fn jn(argument : f64, order : i32) -> f64 {
+    if order < 0 {
+        return reflection_sign(order) * jn(argument, -order);
+    }
+
     let mut factor = 1.0;
     let positive_argument = argument.abs();
 
@@ -19,16 +32,121 @@ pub fn jn(argument : f64, order : i32) -> f64 {
         };
     };
 
-    match order {
-        0 => j0_positive(positive_argument) * factor,
-        1 => j1_positive(positive_argument) * factor,
-        o if (o as f64) < positive_argument * PRECISION_EIGHTH_ROOT => large_argument_expansion(o, positive_argument),
+    factor * match order {
+        0 => j0_positive(positive_argument),
+        1 => j1_positive(positive_argument),
+        o if (o as f64) < positive_argument * PRECISION_EIGHTH_ROOT => {
+            let expansion = large_argument_expansion(o, positive_argument);
+            if expansion.error < LARGE_ARGUMENT_TOLERANCE { expansion.value.re } else { bessel_forward_recurrence(o, positive_argument) }
+        },
         o if (o as f64) < positive_argument => bessel_forward_recurrence(o, positive_argument),
         o if positive_argument < 10.0 || 0.1 * positive_argument * positive_argument / 4.0 < (o as f64) => small_argument_expansion(o, positive_argument),
         o => backwards_recurrence(o, positive_argument)
     }
 }
 
+/// The Bessel function of the second kind (DLMF 10.2.3), which is not defined for negative real values
+pub fn yn(argument : f64, order : i32) -> f64 {
+    if order < 0 {
+        return reflection_sign(order) * yn(argument, -order);
+    }
+
+    if argument == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    match order {
+        0 => libm::y0(argument),
+        1 => libm::y1(argument),
+        o if (o as f64) < argument * PRECISION_EIGHTH_ROOT => {
+            let expansion = large_argument_expansion(o, argument);
+            if expansion.error < LARGE_ARGUMENT_TOLERANCE { expansion.value.im } else { neumann_forward_recurrence(o, argument) }
+        },
+        // Y_n dominates J_n as the order grows, so forward recurrence is stable whatever the argument
+        o => neumann_forward_recurrence(o, argument)
+    }
+}
+
+/// The Hankel function of the first kind J_n + i Y_n (DLMF 10.4.3), for positive arguments
+pub fn h1n(argument : f64, order : i32) -> Complex64 {
+    Complex64::new(jn(argument, order), yn(argument, order))
+}
+
+/// The Hankel function of the second kind J_n - i Y_n (DLMF 10.4.3), for positive arguments
+pub fn h2n(argument : f64, order : i32) -> Complex64 {
+    Complex64::new(jn(argument, order), -yn(argument, order))
+}
+
+/// The modified Bessel function of the first kind (DLMF 10.25.2)
+pub fn i_n(argument : f64, order : i32) -> f64 {
+    let scaled = i_n_scaled(argument, order);
+    if scaled == 0.0 { 0.0 } else { scaled * argument.abs().exp() }
+}
+
+/// e^(-|x|) I_n(x), which stays finite where I_n overflows
+pub fn i_n_scaled(argument : f64, order : i32) -> f64 {
+    // DLMF 10.27.1
+    let order = order.abs();
+    let factor = if argument < 0.0 { reflection_sign(order) } else { 1.0 };
+    let positive_argument = argument.abs();
+
+    if positive_argument == 0.0 {
+        return if order == 0 { 1.0 } else { 0.0 };
+    }
+
+    factor * match order {
+        o if (o as f64) < positive_argument * PRECISION_EIGHTH_ROOT => {
+            // I_n has no zeros to hide behind an absolute tolerance, so the expansion must be accurate relative to its value
+            let expansion = modified_large_argument_expansion(o, positive_argument);
+            if expansion.error < 4.0 * f64::EPSILON * expansion.value { expansion.value } else { modified_backwards_recurrence(o, positive_argument) }
+        },
+        o if positive_argument < 10.0 || 0.1 * positive_argument * positive_argument / 4.0 < (o as f64) => modified_small_argument_expansion(o, positive_argument),
+        o => modified_backwards_recurrence(o, positive_argument)
+    }
+}
+
+/// The modified Bessel function of the second kind (DLMF 10.25.3), which is not defined for negative real values
+pub fn k_n(argument : f64, order : i32) -> f64 {
+    let scaled = k_n_scaled(argument, order);
+    if scaled.is_infinite() { scaled } else { scaled * (-argument).exp() }
+}
+
+/// e^x K_n(x), which stays finite where K_n underflows
+pub fn k_n_scaled(argument : f64, order : i32) -> f64 {
+    if argument < 0.0 {
+        return f64::NAN;
+    }
+
+    if argument == 0.0 {
+        return f64::INFINITY;
+    }
+
+    // K_n dominates I_n as the order grows, so forward recurrence is stable whatever the argument (DLMF 10.27.3, 10.29.1)
+    let fac = 2.0 / argument;
+    let mut prev = k0_scaled(argument);
+    let mut curr = k1_scaled(argument);
+    if order == 0 {
+        return prev;
+    }
+
+    for i in 1..order.abs() {
+        if !curr.is_finite() {
+            break;
+        }
+
+        let value = (i as f64) * fac * curr + prev;
+        prev = curr;
+        curr = value;
+    }
+
+    curr
+}
+
+// (-1)^n, from J_-n = (-1)^n J_n and its analogues (DLMF 10.4.1, 10.4.2)
+fn reflection_sign(order : i32) -> f64 {
+    if order % 2 == 0 { 1.0 } else { -1.0 }
+}
+
 fn j0_positive(argument : f64) -> f64 {
     // #[cfg(test)]
     // println!("J0");
@@ -51,38 +169,56 @@ fn j1_positive(argument : f64) -> f64 {
     libm::j1(argument)
 }
 
-// MmcMahon
-// Large argument expansion as used in Florentin, J. J., Milton Abramowitz and Irene A. Stegun. �Handbook of Mathematical Functions.� American Mathematical Monthly 73 (1966): 1143. 9.2.28 & 9.2.29
-fn large_argument_expansion(order : i32, argument : f64) -> f64 {
+fn k0_scaled(argument : f64) -> f64 {
+    scaled_modified_bessel(Complex64::new(argument, 0.0), 0.0).1.re
+}
+
+fn k1_scaled(argument : f64) -> f64 {
+    scaled_modified_bessel(Complex64::new(argument, 0.0), 1.0).1.re
+}
+
+// Hankel's large argument expansion (DLMF 10.17.5) of H^(1) = J + i Y = sqrt(2 / (pi x)) e^(i omega) sum_k i^k a_k(order) / x^k.
+// The sum is truncated at its smallest term, so the error returned bounds the truncation error of both J and Y.
+fn large_argument_expansion(order : i32, argument : f64) -> WithError<Complex64> {
     // #[cfg(test)]
     // println!("Large argument");
 
-    let mu = (4 * order * order) as f64;
+    let mu = 4.0 * (order as f64) * (order as f64);
+    let mut term = Complex64::new(1.0, 0.0);
+    let series = asymptotic_series(f64::EPSILON, 0, LARGE_ARGUMENT_MAX_TERMS, |k| {
+        if k > 0 {
+            let odd = (2 * k - 1) as f64;
+            term = term * Complex64::i() * (mu - odd * odd) / (8.0 * k as f64 * argument);
+        }
+        term
+    });
 
-    let argument_cubed = argument * argument * argument;
-    let argument_to_five = argument_cubed * argument * argument;
-    let argument_to_seven = argument_to_five * argument * argument;
+    let prefactor = (2.0 / (PI * argument)).sqrt();
+    let phase = Complex64::from_polar(1.0, argument - (0.5 * (order as f64) + 0.25) * PI);
 
-    let large_expansion_denom = 4.0 * argument * argument;
-    let large_expansion_denom_squared = large_expansion_denom * large_expansion_denom;
-    let large_expansion_denom_cubed = large_expansion_denom_squared * large_expansion_denom;
+    WithError {
+        value : prefactor * phase * series.value,
+        error : prefactor * series.error,
+    }
+}
 
-    let prefactor = (2.0 / (PI * argument)).sqrt();
+// Hankel's expansion of e^-x I_n(x) (DLMF 10.40.1), dropping the exponentially small second series, which is well below the truncation error.
+fn modified_large_argument_expansion(order : i32, argument : f64) -> WithError<f64> {
+    let mu = 4.0 * (order as f64) * (order as f64);
+    let mut term = 1.0;
+    let series = asymptotic_series(f64::EPSILON, 0, LARGE_ARGUMENT_MAX_TERMS, |k| {
+        if k > 0 {
+            let odd = (2 * k - 1) as f64;
+            term *= -(mu - odd * odd) / (8.0 * k as f64 * argument);
+        }
+        term
+    });
 
-    prefactor * f64::sqrt(
-        1.0
-        + 0.5 * (mu - 1.0) / large_expansion_denom
-        + 0.375 * (mu - 1.0) * (mu - 9.0) / large_expansion_denom_squared
-        + 0.3125 * (mu - 1.0) * (mu - 9.0) * (mu - 25.0) / large_expansion_denom_cubed
-    )
-    * f64::cos(
-        argument
-        - (0.5 * (order as f64) + 0.25) * PI
-        + (mu - 1.0) / (8.0 * argument)
-        + (mu - 1.0) * (mu - 25.0) / (384.0 * argument_cubed)
-        + (mu - 1.0) * (mu * mu - 144.0 * mu + 1073.0) / (5120.0 * argument_to_five)
-        + (mu - 1.0) * (5.0 * mu * mu * mu - 1535.0 * mu * mu + 54703.0 * mu - 375733.0) / (229376.0 * argument_to_seven)
-    )
+    let prefactor = 1.0 / (2.0 * PI * argument).sqrt();
+    WithError {
+        value : prefactor * series.value,
+        error : prefactor * series.error,
+    }
 }
 
 fn bessel_forward_recurrence(order : i32, argument : f64) -> f64 {
@@ -102,6 +238,24 @@ fn bessel_forward_recurrence(order : i32, argument : f64) -> f64 {
     curr
 }
 
+fn neumann_forward_recurrence(order : i32, argument : f64) -> f64 {
+    let fac = 2.0 / argument;
+    let mut prev = libm::y0(argument);
+    let mut curr = libm::y1(argument);
+    for i in 1..order {
+        // Once it has overflowed, carrying on would only turn -inf into NaN
+        if !curr.is_finite() {
+            break;
+        }
+
+        let value = (i as f64) * fac * curr - prev;
+        prev = curr;
+        curr = value;
+    }
+
+    curr
+}
+
 // DLMF 10.2.2
 fn small_argument_expansion(order : i32, argument : f64) -> f64 {
     // #[cfg(test)]
@@ -127,6 +281,55 @@ fn small_argument_expansion(order : i32, argument : f64) -> f64 {
     }
 }
 
+// e^-x I_n(x) from DLMF 10.25.2, with every term positive
+fn modified_small_argument_expansion(order : i32, argument : f64) -> f64 {
+    let argument_over_two_squared = argument * argument / 4.0;
+    let mut sum_result = 1.0;
+    let mut current_sum_addition = 1.0;
+    let mut k = 1;
+    while current_sum_addition > f64::EPSILON * sum_result {
+        current_sum_addition *= argument_over_two_squared / ((k as f64) * ((k + order) as f64));
+        sum_result += current_sum_addition;
+        k += 1;
+    }
+
+    sum_result * f64::exp(order as f64 * (argument / 2.0).ln() - libm::lgamma(order as f64 + 1.0) - argument)
+}
+
+// Miller's algorithm: recur downwards from well above both the order and the argument, where I_k is negligible,
+// then normalise with e^x = I_0(x) + 2 sum_k I_k(x) (DLMF 10.35.5), which also gives the scaled value directly
+fn modified_backwards_recurrence(order : i32, argument : f64) -> f64 {
+    let size = (order as f64).max(argument);
+    let start = (size + 10.0 * size.sqrt() + 30.0) as i32;
+    let fac = 2.0 / argument;
+    let mut next = 0.0;
+    let mut curr = 1.0;
+    let mut result = 0.0;
+    let mut normalisation = 0.0;
+    for k in (1..=start).rev() {
+        if k == order {
+            result = curr;
+        }
+        normalisation += 2.0 * curr;
+
+        let prev = (k as f64) * fac * curr + next;
+        next = curr;
+        curr = prev;
+        if curr > 1e250 {
+            next *= 1e-250;
+            curr *= 1e-250;
+            result *= 1e-250;
+            normalisation *= 1e-250;
+        }
+    }
+
+    if order == 0 {
+        result = curr;
+    }
+
+    result / (normalisation + curr)
+}
+
 fn backwards_recurrence(order : i32, argument : f64) -> f64 {
     // #[cfg(test)]
     // println!("Backwards recurrence");
@@ -138,6 +341,8 @@ fn backwards_recurrence(order : i32, argument : f64) -> f64 {
 mod tests {
     extern crate paste;
 
+    use num::complex::Complex64;
+
     macro_rules! grid_bessel_tests {
         ($argumentList:tt; [$($order:literal),*]) => {
             $(
@@ -170,5 +375,103 @@ mod tests {
         };
     }
 
+    #[test]
+    fn large_argument_expansion_is_self_checking() {
+        let accurate = super::large_argument_expansion(3, 1000.0);
+        assert!(accurate.error < 1e-15);
+        assert!((accurate.value.re - libm::jn(3, 1000.0)).abs() < 1e-14);
+        assert!((accurate.value.im - libm::yn(3, 1000.0)).abs() < 1e-14);
+
+        // Here the terms grow from the start, so the expansion reports itself as useless and jn falls back to recurrence
+        assert!(super::large_argument_expansion(300, 600.0).error >= 1.0 * (2.0 / (std::f64::consts::PI * 600.0)).sqrt());
+        assert!((super::jn(600.0, 300) - libm::jn(300, 600.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn odd_orders_change_sign_at_negative_arguments() {
+        for order in [3, 5, 41] {
+            for argument in [2e-4, 5.0, 10.0, 60.0, 25e3] {
+                let my_result = super::jn(-argument, order);
+                let their_result = libm::jn(order, -argument);
+                println!("J_{order}(-{argument}): I got {my_result}, but libm got {their_result}");
+                assert_eq!(my_result, -super::jn(argument, order));
+                assert!((my_result - their_result).abs() < 0.02);
+            }
+        }
+    }
+
+    #[test]
+    fn neumann_matches_libm() {
+        for order in [0, 1, 2, 3, 10, 400, 7000] {
+            for argument in [2e-4, 5.0, 10.0, 60.0, 1000.0, 25e3] {
+                let my_result = super::yn(argument, order);
+                let their_result = libm::yn(order, argument);
+                println!("Y_{order}({argument}): I got {my_result}, but libm got {their_result}");
+                // Near a zero only the error relative to the envelope sqrt(2 / (pi x)) is meaningful
+                let scale = their_result.abs().max((2.0 / (std::f64::consts::PI * argument)).sqrt());
+                assert!(my_result == their_result || (my_result - their_result).abs() <= 1e-10 * scale);
+            }
+        }
+
+        assert_eq!(super::yn(0.0, 2), f64::NEG_INFINITY);
+        assert!(super::yn(-1.0, 2).is_nan());
+    }
+
+    #[test]
+    fn negative_orders_reflect() {
+        for argument in [0.5, 7.0, 300.0] {
+            assert_eq!(super::jn(argument, -3), -super::jn(argument, 3));
+            assert_eq!(super::yn(argument, -4), super::yn(argument, 4));
+            assert_eq!(super::h1n(argument, -5), -super::h1n(argument, 5));
+        }
+    }
+
+    #[test]
+    fn hankel_functions_satisfy_wronskian() {
+        // DLMF 10.5.5: H^(1)_n+1 H^(2)_n - H^(1)_n H^(2)_n+1 = -4i / (pi x)
+        for (argument, order) in [(0.3, 0), (4.0, 2), (50.0, 7), (2000.0, 30)] {
+            let wronskian = super::h1n(argument, order + 1) * super::h2n(argument, order) - super::h1n(argument, order) * super::h2n(argument, order + 1);
+            let expected = Complex64::new(0.0, -4.0 / (std::f64::consts::PI * argument));
+            assert!((wronskian - expected).norm() < 1e-12 * expected.norm(), "{wronskian} against {expected}");
+        }
+    }
+
+    #[test]
+    fn modified_functions_match_reference_values() {
+        // Reference values from mpmath, as (order, argument, e^-x I_n(x), e^x K_n(x))
+        let cases = [
+            (0, 1e-3, 0.9990007495835156, 7.030716002378251),
+            (0, 0.5, 0.6450352704491501, 1.5241093857739094),
+            (1, 3.0, 0.19682671329730086, 0.8065634801287869),
+            (2, 12.0, 0.09784883799841028, 0.4203335040506965),
+            (5, 30.0, 0.047925203168721224, 0.3430714745912582),
+            (0, 700.0, 0.015081295651531358, 0.04736236945461357),
+            (40, 2.0, 1.699634128298426e-49, 7.345342357886154e46),
+            (40, 80.0, 2.3278627121036574e-6, 2401.4173599276273),
+            (3, 1000.0, 0.01256056218254712, 0.039806961284409734),
+            (200, 150.0, 2.5534213606724004e-54, 7.832615732173226e50),
+        ];
+        for (order, argument, i, k) in cases {
+            println!("Order {order} at {argument}: {} {}", super::i_n_scaled(argument, order), super::k_n_scaled(argument, order));
+            assert!((super::i_n_scaled(argument, order) - i).abs() < 1e-12 * i);
+            assert!((super::k_n_scaled(argument, order) - k).abs() < 1e-12 * k);
+        }
+
+        assert!((super::i_n_scaled(-4.0, 7) + 0.0007569846867715783).abs() < 1e-15);
+        assert_eq!(super::i_n(1000.0, 3), f64::INFINITY);
+        assert_eq!(super::k_n(1000.0, 3), 0.0);
+        assert_eq!(super::i_n_scaled(2.0, -3), super::i_n_scaled(2.0, 3));
+    }
+
+    #[test]
+    fn modified_functions_satisfy_wronskian() {
+        // DLMF 10.28.2: I_n K_n+1 + I_n+1 K_n = 1 / x, where the scalings cancel
+        for (argument, order) in [(0.01, 0), (1.0, 3), (15.0, 2), (60.0, 100), (5000.0, 10)] {
+            let wronskian = super::i_n_scaled(argument, order) * super::k_n_scaled(argument, order + 1)
+                + super::i_n_scaled(argument, order + 1) * super::k_n_scaled(argument, order);
+            assert!((wronskian * argument - 1.0).abs() < 1e-12, "{wronskian} against {}", 1.0 / argument);
+        }
+    }
+
     grid_bessel_tests!{ [25e3, 2e-5, 5, 10, 10e-3, 6e1, 2e-4]; [2, 10, 400, 7000, 40000, 3000000] }
 }
\ No newline at end of file
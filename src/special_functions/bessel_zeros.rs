@@ -0,0 +1,162 @@
+use std::f64::consts::PI;
+
+use super::{jn, yn};
+
+const MAX_ITERATIONS : usize = 50;
+
+/// The first `count` positive zeros j_{n,1}, j_{n,2}, ... of the Bessel function J_n (DLMF 10.21.i)
+pub fn jn_zeros(order : i32, count : usize) -> Vec<f64> {
+    zeros(jn, order.abs(), count, false, 1.0)
+}
+
+/// The first `count` positive zeros of J_n'. Unlike DLMF, which counts x = 0 as the first zero of J_0', only positive zeros are returned, as needed for the TE modes of a circular waveguide.
+pub fn jn_prime_zeros(order : i32, count : usize) -> Vec<f64> {
+    // J_0' = -J_1 (DLMF 10.6.3), whose first zero is at the origin and out of McMahon's reach
+    if order == 0 {
+        return jn_zeros(1, count);
+    }
+
+    zeros(jn, order.abs(), count, true, 3.0)
+}
+
+/// The first `count` positive zeros y_{n,1}, y_{n,2}, ... of the Bessel function Y_n (DLMF 10.21.i)
+pub fn yn_zeros(order : i32, count : usize) -> Vec<f64> {
+    zeros(yn, order.abs(), count, false, 3.0)
+}
+
+/// The first `count` positive zeros of Y_n'
+pub fn yn_prime_zeros(order : i32, count : usize) -> Vec<f64> {
+    zeros(yn, order.abs(), count, true, 1.0)
+}
+
+// Each zero is refined by Halley's method from a starting guess.
+// McMahon's expansion (DLMF 10.21.19) is the guess whenever the zero is far enough from the turning point at x = n.
+// Otherwise the first two zeros come from the large order expansion in terms of Airy zeros (DLMF 10.21.43),
+// and later ones are extrapolated from the spacing of the two before, which shrinks slowly towards pi.
+// The phase offset is 1 for j_{n,m} and y'_{n,m} and 3 for j'_{n,m} and y_{n,m}, as in a = (m + n / 2 - offset / 4) pi.
+fn zeros<TFunc>(function : TFunc, order : i32, count : usize, derivative : bool, phase_offset : f64) -> Vec<f64>
+    where TFunc : Fn(f64, i32) -> f64 {
+    let mut zeros : Vec<f64> = Vec::with_capacity(count);
+    for m in 1..=count {
+        let guess = if 4 * m as i32 + 10 > order {
+            mcmahon_expansion(order, m, derivative, phase_offset)
+        }
+        else if m <= 2 {
+            large_order_expansion(order, m, derivative, phase_offset)
+        }
+        else {
+            2.0 * zeros[m - 2] - zeros[m - 3]
+        };
+
+        zeros.push(halley_refinement(&function, order, guess, derivative));
+    }
+
+    zeros
+}
+
+// DLMF 10.21.19 and 10.21.20, to the fourth term
+fn mcmahon_expansion(order : i32, m : usize, derivative : bool, phase_offset : f64) -> f64 {
+    let mu = 4.0 * (order as f64) * (order as f64);
+    let a = (m as f64 + order as f64 / 2.0 - phase_offset / 4.0) * PI;
+    let eight_a = 8.0 * a;
+    if derivative {
+        a - (mu + 3.0) / eight_a
+            - 4.0 * (7.0 * mu * mu + 82.0 * mu - 9.0) / (3.0 * eight_a.powi(3))
+            - 32.0 * (83.0 * mu.powi(3) + 2075.0 * mu * mu - 3039.0 * mu + 3537.0) / (15.0 * eight_a.powi(5))
+    }
+    else {
+        a - (mu - 1.0) / eight_a
+            - 4.0 * (mu - 1.0) * (7.0 * mu - 31.0) / (3.0 * eight_a.powi(3))
+            - 32.0 * (mu - 1.0) * (83.0 * mu * mu - 982.0 * mu + 3779.0) / (15.0 * eight_a.powi(5))
+    }
+}
+
+// DLMF 10.21.43 and its analogues for j', y and y', with the zeros of Ai, Ai', Bi and Bi' from DLMF 9.9.6 to 9.9.13
+fn large_order_expansion(order : i32, m : usize, derivative : bool, phase_offset : f64) -> f64 {
+    let t = 3.0 * PI / 8.0 * (4.0 * m as f64 - phase_offset);
+    let airy_zero = if derivative {
+        -t.powf(2.0 / 3.0) * (1.0 - 7.0 / 48.0 * t.powi(-2) + 35.0 / 288.0 * t.powi(-4))
+    }
+    else {
+        -t.powf(2.0 / 3.0) * (1.0 + 5.0 / 48.0 * t.powi(-2) - 5.0 / 36.0 * t.powi(-4))
+    };
+
+    let order = order as f64;
+    order - airy_zero * (order / 2.0).cbrt() + 3.0 / 20.0 * airy_zero * airy_zero * (order / 2.0).cbrt().powi(-1)
+}
+
+// Halley's method on C_n or C_n', with the derivatives from C_n' = C_n-1 - n C_n / x (DLMF 10.6.2) and Bessel's equation (DLMF 10.2.1).
+// This only ever evaluates C_n-1 and C_n beyond the turning point, where jn and yn are at their most accurate.
+fn halley_refinement<TFunc>(function : &TFunc, order : i32, guess : f64, derivative : bool) -> f64
+    where TFunc : Fn(f64, i32) -> f64 {
+    let n_squared = (order as f64) * (order as f64);
+    let mut x = guess;
+    for _ in 0..MAX_ITERATIONS {
+        let value = function(x, order);
+        let first = function(x, order - 1) - order as f64 / x * value;
+        let second = -first / x - (1.0 - n_squared / (x * x)) * value;
+        let (f, f_prime, f_double_prime) = if derivative {
+            let third = -second / x + first / (x * x) - (1.0 - n_squared / (x * x)) * first - 2.0 * n_squared / x.powi(3) * value;
+            (first, second, third)
+        }
+        else {
+            (value, first, second)
+        };
+
+        let step = 2.0 * f * f_prime / (2.0 * f_prime * f_prime - f * f_double_prime);
+        x -= step;
+        if step.abs() <= 4.0 * f64::EPSILON * x {
+            break;
+        }
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jn_zeros, jn_prime_zeros, yn_zeros, yn_prime_zeros};
+
+    #[test]
+    fn matches_reference_values() {
+        // From mpmath, as j_{n,m}, j'_{n,m}, y_{n,m} and y'_{n,m}
+        let references = [
+            (0, 1, [2.404825557695773, 3.8317059702075125, 0.8935769662791675, 2.197141326031017]),
+            (0, 10, [30.634606468431976, 32.189679910974405, 29.064030252728397, 30.618286491641115]),
+            (1, 3, [10.173468135062722, 8.536316366346286, 8.596005868331169, 10.123404655436612]),
+            (5, 1, [8.771483815959954, 6.415616375700241, 6.747183824871022, 8.649556243697198]),
+            (5, 20, [69.72289116171673, 68.1405725741757, 68.14798902724003, 69.71564572747401]),
+            (40, 1, [46.64840949828574, 42.78537226039299, 43.262361129824995, 46.501369730416535]),
+            (40, 7, [72.73604574402468, 70.82944334351896, 70.84464383216724, 72.72192570338238]),
+        ];
+
+        for (order, m, expected) in references {
+            let zeros = [jn_zeros(order, m)[m - 1], jn_prime_zeros(order, m)[m - 1], yn_zeros(order, m)[m - 1], yn_prime_zeros(order, m)[m - 1]];
+            for (zero, expected) in zeros.into_iter().zip(expected) {
+                println!("Order {order}, zero {m}: {zero} against {expected}");
+                assert!((zero - expected).abs() <= 1e-13 * expected);
+            }
+        }
+        assert_eq!(jn_zeros(-3, 4), jn_zeros(3, 4));
+    }
+
+    #[test]
+    fn zeros_interlace() {
+        // n <= j'_{n,1} < y_{n,1} < y'_{n,1} < j_{n,1} < j'_{n,2} < ... (DLMF 10.21.3) and j_{n,m} < j_{n+1,m} < j_{n,m+1} (DLMF 10.21.2)
+        for order in [1, 4, 25, 90] {
+            let j = jn_zeros(order, 40);
+            let j_prime = jn_prime_zeros(order, 40);
+            let y = yn_zeros(order, 40);
+            let y_prime = yn_prime_zeros(order, 40);
+            let j_next = jn_zeros(order + 1, 40);
+            assert!(order as f64 <= j_prime[0]);
+            for m in 0..40 {
+                assert!(j_prime[m] < y[m] && y[m] < y_prime[m] && y_prime[m] < j[m]);
+                assert!(j[m] < j_next[m]);
+                if m + 1 < 40 {
+                    assert!(j[m] < j_prime[m + 1] && j_next[m] < j[m + 1]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use num::{complex::Complex64, Zero};
+
+const CARLSON_MAX_ITERATIONS : i32 = 100;
+
+/// Carlson's symmetric elliptic integral of the first kind R_F(x, y, z) (DLMF 19.16.1), by the duplication algorithm of Carlson (1995).
+/// It holds for arguments off the negative real axis, with the square roots on their principal branches, and is infinite when two of them are zero.
+pub fn carlson_rf(x : Complex64, y : Complex64, z : Complex64) -> Complex64 {
+    if [x, y, z].iter().filter(|argument| argument.is_zero()).count() > 1 {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    let (x0, y0) = (x, y);
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut mean = (x + y + z) / 3.0;
+    let mean0 = mean;
+    let bound = (3.0 * f64::EPSILON).powf(-1.0 / 6.0) * (mean - x).norm().max((mean - y).norm()).max((mean - z).norm());
+
+    // 4^-n after n duplications
+    let mut scale = 1.0;
+    for _ in 0..CARLSON_MAX_ITERATIONS {
+        if scale * bound < mean.norm() {
+            break;
+        }
+
+        let (root_x, root_y, root_z) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = root_x * root_y + root_x * root_z + root_y * root_z;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+        mean = (mean + lambda) / 4.0;
+        scale /= 4.0;
+    }
+
+    let big_x = (mean0 - x0) * scale / mean;
+    let big_y = (mean0 - y0) * scale / mean;
+    let big_z = -(big_x + big_y);
+    let e2 = big_x * big_y - big_z * big_z;
+    let e3 = big_x * big_y * big_z;
+    (1.0 - e2 / 10.0 + e3 / 14.0 + e2 * e2 / 24.0 - 3.0 * e2 * e3 / 44.0) / mean.sqrt()
+}
+
+/// Carlson's degenerate elliptic integral R_C(x, y) = R_F(x, y, y) (DLMF 19.2.17), by its own duplication algorithm.
+/// For real y < 0 it is the Cauchy principal value (DLMF 19.2.20), and it is infinite for y = 0.
+pub fn carlson_rc(x : Complex64, y : Complex64) -> Complex64 {
+    if y.is_zero() {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    if y.im == 0.0 && y.re < 0.0 {
+        // DLMF 19.2.20
+        return (x / (x - y)).sqrt() * carlson_rc(x - y, -y);
+    }
+
+    let (mut x, mut y) = (x, y);
+    let mut mean = (x + 2.0 * y) / 3.0;
+    let (mean0, y0) = (mean, y);
+    let bound = (3.0 * f64::EPSILON).powf(-1.0 / 8.0) * (mean - x).norm();
+
+    let mut scale = 1.0;
+    for _ in 0..CARLSON_MAX_ITERATIONS {
+        if scale * bound < mean.norm() {
+            break;
+        }
+
+        let lambda = 2.0 * x.sqrt() * y.sqrt() + y;
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        mean = (mean + lambda) / 4.0;
+        scale /= 4.0;
+    }
+
+    let s = (y0 - mean0) * scale / mean;
+    let series = 1.0 + s * s * (3.0 / 10.0 + s * (1.0 / 7.0 + s * (3.0 / 8.0 + s * (9.0 / 22.0 + s * (159.0 / 208.0 + s * 9.0 / 8.0)))));
+    series / mean.sqrt()
+}
+
+/// Carlson's symmetric elliptic integral of the third kind R_J(x, y, z, p) (DLMF 19.16.2), by the duplication algorithm of Carlson (1995).
+/// For real non-negative x, y and z and real p < 0 it is the Cauchy principal value (DLMF 19.20.14),
+/// and it is infinite for p = 0 or when two of x, y and z are zero.
+pub fn carlson_rj(x : Complex64, y : Complex64, z : Complex64, p : Complex64) -> Complex64 {
+    if p.is_zero() || [x, y, z].iter().filter(|argument| argument.is_zero()).count() > 1 {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    if x.im == 0.0 && y.im == 0.0 && z.im == 0.0 && p.im == 0.0 && x.re >= 0.0 && y.re >= 0.0 && z.re >= 0.0 && p.re < 0.0 {
+        return carlson_rj_principal_value(x.re, y.re, z.re, -p.re);
+    }
+
+    let (x0, y0, z0) = (x, y, z);
+    let (mut x, mut y, mut z, mut p) = (x, y, z, p);
+    let mut mean = (x + y + z + 2.0 * p) / 5.0;
+    let mean0 = mean;
+    let delta = (p - x) * (p - y) * (p - z);
+    let bound = (f64::EPSILON / 4.0).powf(-1.0 / 6.0)
+        * (mean - x).norm().max((mean - y).norm()).max((mean - z).norm()).max((mean - p).norm());
+
+    let mut scale = 1.0;
+    let mut sum = Complex64::new(0.0, 0.0);
+    for _ in 0..CARLSON_MAX_ITERATIONS {
+        if scale * bound < mean.norm() {
+            break;
+        }
+
+        let (root_x, root_y, root_z, root_p) = (x.sqrt(), y.sqrt(), z.sqrt(), p.sqrt());
+        let lambda = root_x * root_y + root_x * root_z + root_y * root_z;
+        let d = (root_p + root_x) * (root_p + root_y) * (root_p + root_z);
+        let e = scale * scale * scale * delta / (d * d);
+        sum += scale * carlson_rc(Complex64::new(1.0, 0.0), 1.0 + e) / d;
+
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+        p = (p + lambda) / 4.0;
+        mean = (mean + lambda) / 4.0;
+        scale /= 4.0;
+    }
+
+    let big_x = (mean0 - x0) * scale / mean;
+    let big_y = (mean0 - y0) * scale / mean;
+    let big_z = (mean0 - z0) * scale / mean;
+    let big_p = -(big_x + big_y + big_z) / 2.0;
+    let e2 = big_x * big_y + big_x * big_z + big_y * big_z - 3.0 * big_p * big_p;
+    let e3 = big_x * big_y * big_z + 2.0 * e2 * big_p + 4.0 * big_p * big_p * big_p;
+    let e4 = (2.0 * big_x * big_y * big_z + e2 * big_p + 3.0 * big_p * big_p * big_p) * big_p;
+    let e5 = big_x * big_y * big_z * big_p * big_p;
+    scale * fourth_order_series(e2, e3, e4, e5) / (mean * mean.sqrt()) + 6.0 * sum
+}
+
+/// Carlson's symmetric elliptic integral of the second kind R_D(x, y, z) = R_J(x, y, z, z) (DLMF 19.16.5), by its own duplication algorithm.
+/// It is infinite for z = 0 or when x and y are both zero.
+pub fn carlson_rd(x : Complex64, y : Complex64, z : Complex64) -> Complex64 {
+    if z.is_zero() || (x.is_zero() && y.is_zero()) {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    let (x0, y0) = (x, y);
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut mean = (x + y + 3.0 * z) / 5.0;
+    let mean0 = mean;
+    let bound = (f64::EPSILON / 4.0).powf(-1.0 / 6.0) * (mean - x).norm().max((mean - y).norm()).max((mean - z).norm());
+
+    let mut scale = 1.0;
+    let mut sum = Complex64::new(0.0, 0.0);
+    for _ in 0..CARLSON_MAX_ITERATIONS {
+        if scale * bound < mean.norm() {
+            break;
+        }
+
+        let (root_x, root_y, root_z) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = root_x * root_y + root_x * root_z + root_y * root_z;
+        sum += scale / (root_z * (z + lambda));
+
+        x = (x + lambda) / 4.0;
+        y = (y + lambda) / 4.0;
+        z = (z + lambda) / 4.0;
+        mean = (mean + lambda) / 4.0;
+        scale /= 4.0;
+    }
+
+    let big_x = (mean0 - x0) * scale / mean;
+    let big_y = (mean0 - y0) * scale / mean;
+    let big_z = -(big_x + big_y) / 3.0;
+    let xy = big_x * big_y;
+    let z_squared = big_z * big_z;
+    let e2 = xy - 6.0 * z_squared;
+    let e3 = (3.0 * xy - 8.0 * z_squared) * big_z;
+    let e4 = 3.0 * (xy - z_squared) * z_squared;
+    let e5 = xy * z_squared * big_z;
+    scale * fourth_order_series(e2, e3, e4, e5) / (mean * mean.sqrt()) + 3.0 * sum
+}
+
+// The truncated series in the elementary symmetric functions shared by R_J and R_D
+fn fourth_order_series(e2 : Complex64, e3 : Complex64, e4 : Complex64, e5 : Complex64) -> Complex64 {
+    1.0 - 3.0 * e2 / 14.0 + e3 / 6.0 + 9.0 * e2 * e2 / 88.0 - 3.0 * e4 / 22.0 - 9.0 * e2 * e3 / 52.0 + 3.0 * e5 / 26.0
+}
+
+// DLMF 19.20.14, which gives the principal value for p = -q from R_J at a positive p, with y the middle one of x, y and z
+fn carlson_rj_principal_value(x : f64, y : f64, z : f64, q : f64) -> Complex64 {
+    let mut sorted = [x, y, z];
+    sorted.sort_by(f64::total_cmp);
+    let [x, y, z] = sorted;
+
+    let p = y + (z - y) * (y - x) / (y + q);
+    let c = |value : f64| Complex64::new(value, 0.0);
+    let rj = carlson_rj(c(x), c(y), c(z), c(p));
+    let rf = carlson_rf(c(x), c(y), c(z));
+    let rc = carlson_rc(c(x * z + p * q), c(p * q));
+    ((p - y) * rj - 3.0 * rf + 3.0 * (x * y * z / (x * z + p * q)).sqrt() * rc) / (y + q)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use num::complex::Complex64;
+
+    use super::{carlson_rf, carlson_rc, carlson_rj, carlson_rd};
+    use crate::special_functions::test_support::c;
+
+    fn r(x : f64) -> Complex64 {
+        Complex64::new(x, 0.0)
+    }
+
+    #[test]
+    fn matches_reference_values() {
+        // From mpmath, as elliprf, elliprc, elliprj and elliprd at the test points of Carlson (1995)
+        let references = [
+            (carlson_rf(r(1.0), r(2.0), r(0.0)), r(1.3110287771460598)),
+            (carlson_rf(r(2.0), r(3.0), r(4.0)), r(0.5840828416771517)),
+            (carlson_rf(c(0.0, 1.0), c(0.0, -1.0), r(0.0)), r(1.8540746773013719)),
+            (carlson_rf(c(-1.0, 1.0), c(0.0, 1.0), c(1.0, -1.0)), c(0.9391205021861937, -0.5329625201863527)),
+            (carlson_rf(c(-1.0, 1.0), c(0.0, 1.0), r(0.0)), c(0.7961258658423391, -1.213856669836496)),
+            (carlson_rc(r(0.0), r(0.25)), r(PI)),
+            (carlson_rc(r(2.25), r(2.0)), r(2.0f64.ln())),
+            (carlson_rc(c(0.0, 1.0), c(0.0, -1.0)), c(1.2260849569072199, 0.3447113698876768)),
+            (carlson_rc(c(0.0, -1.0), c(0.0, 1.0)), c(1.2260849569072199, -0.3447113698876768)),
+            (carlson_rc(c(0.0, 1.0), r(-1.0)), c(0.7777859692044738, 0.19832484993428773)),
+            (carlson_rj(r(0.0), r(1.0), r(2.0), r(3.0)), r(0.7768862377858233)),
+            (carlson_rj(r(2.0), r(3.0), r(4.0), r(5.0)), r(0.14297579667156754)),
+            (carlson_rj(r(2.0), r(3.0), r(4.0), c(-1.0, 1.0)), c(0.13613945827770535, -0.38207561624427167)),
+            (carlson_rj(c(0.0, 1.0), c(0.0, -1.0), r(0.0), r(2.0)), r(1.6490011662710884)),
+            (carlson_rj(c(-1.0, 1.0), c(-1.0, -1.0), r(1.0), r(2.0)), r(0.9414835884122024)),
+            (carlson_rj(c(0.0, 1.0), c(0.0, -1.0), r(0.0), c(1.0, -1.0)), c(1.8260115229009317, 1.2290661908643472)),
+            (carlson_rj(c(-1.0, 1.0), c(-1.0, -1.0), r(1.0), c(-3.0, 1.0)), c(-0.6112797081202818, -1.0684038390006807)),
+            (carlson_rj(c(-1.0, 2.0), c(-1.0, -1.0), r(1.0), c(2.0, 3.0)), c(0.41159451621535526, -0.351857793922769)),
+            (carlson_rd(r(0.0), r(2.0), r(1.0)), r(1.7972103521033884)),
+            (carlson_rd(r(2.0), r(3.0), r(4.0)), r(0.16510527294261054)),
+            (carlson_rd(c(0.0, 1.0), c(0.0, -1.0), r(2.0)), r(0.6593385415421977)),
+            (carlson_rd(c(-2.0, -1.0), c(0.0, -1.0), c(-1.0, 1.0)), c(1.8249027393703805, -1.2218475784827036)),
+            (carlson_rd(r(0.0), c(0.0, 1.0), c(0.0, -1.0)), c(1.2708196271909686, 2.7811120159520577)),
+            (carlson_rd(c(0.0, -1.0), c(0.0, 1.0), r(1.0)), r(1.2545339306618846)),
+        ];
+
+        for (value, expected) in references {
+            println!("{value} against {expected}");
+            assert!((value - expected).norm() <= 1e-14 * expected.norm());
+        }
+    }
+
+    #[test]
+    fn principal_values() {
+        // From mpmath, as elliprc(0.25, -2) = ln 2 / 3 and the real part of elliprj(2, 3, 4, -0.5)
+        assert!((carlson_rc(r(0.25), r(-2.0)) - r(2.0f64.ln() / 3.0)).norm() <= 1e-15);
+        let value = carlson_rj(r(2.0), r(3.0), r(4.0), r(-0.5));
+        assert!((value - r(0.24723819703051564)).norm() <= 1e-14);
+        assert_eq!(value, carlson_rj(r(4.0), r(2.0), r(3.0), r(-0.5)));
+
+        // R_D(x, y, z) = R_J(x, y, z, z) and R_C(x, y) = R_F(x, y, y)
+        let (x, y, z) = (c(0.5, 0.3), c(2.0, -1.0), c(1.5, 0.0));
+        assert!((carlson_rd(x, y, z) - carlson_rj(x, y, z, z)).norm() <= 1e-14 * carlson_rd(x, y, z).norm());
+        assert!((carlson_rc(x, y) - carlson_rf(x, y, y)).norm() <= 1e-14 * carlson_rc(x, y).norm());
+    }
+
+    #[test]
+    fn special_cases() {
+        for x in [r(0.3), r(4.0), c(-2.0, 1.0), c(0.5, -3.0)] {
+            // R_F(x, x, x) = R_C(x, x) = x^-1/2 and R_D(x, x, x) = R_J(x, x, x, x) = x^-3/2
+            assert!((carlson_rf(x, x, x) - 1.0 / x.sqrt()).norm() <= 1e-15 * carlson_rf(x, x, x).norm());
+            assert!((carlson_rc(x, x) - 1.0 / x.sqrt()).norm() <= 1e-15 * carlson_rc(x, x).norm());
+            assert!((carlson_rd(x, x, x) - 1.0 / (x * x.sqrt())).norm() <= 1e-14 * carlson_rd(x, x, x).norm());
+            assert!((carlson_rj(x, x, x, x) - 1.0 / (x * x.sqrt())).norm() <= 1e-14 * carlson_rj(x, x, x, x).norm());
+        }
+
+        // Homogeneity of degrees -1/2 and -3/2
+        let (x, y, z, scale) = (c(0.5, 0.3), r(2.0), c(1.0, -1.0), 7.0);
+        assert!((carlson_rf(scale * x, scale * y, scale * z) - carlson_rf(x, y, z) / scale.sqrt()).norm() <= 1e-14 * carlson_rf(x, y, z).norm());
+        assert!((carlson_rd(scale * x, scale * y, scale * z) - carlson_rd(x, y, z) / scale.powf(1.5)).norm() <= 1e-14 * carlson_rd(x, y, z).norm());
+
+        assert!(carlson_rf(r(0.0), r(0.0), r(1.0)).re.is_infinite());
+        assert!(carlson_rc(r(1.0), r(0.0)).re.is_infinite());
+        assert!(carlson_rd(r(1.0), r(1.0), r(0.0)).re.is_infinite());
+        assert!(carlson_rj(r(1.0), r(2.0), r(3.0), r(0.0)).re.is_infinite());
+    }
+}
@@ -1,32 +1,89 @@
-use std::f64::consts::PI;
+use num::complex::Complex64;
 
-use num::complex::{Complex64, ComplexFloat};
+use super::carlson_rf;
 
-use crate::iterative_calculation;
-
-/// Complete elliptic integral of the first kind (DLMF 19.2.8a)
+/// Complete elliptic integral of the first kind K(k) (DLMF 19.2.8) of the modulus k, from Carlson's R_F (DLMF 19.25.1).
+/// It has branch cuts where k^2 is real and greater than 1, and takes the value from above them there, K(k^2 + i0).
 pub fn k(x : Complex64) -> Complex64 {
-    if x.abs() < 1.0 {
-        let mut k_n = 1.0;
-        // Handle the n=0 term separately, and then for the rest we modify k appropriately and add the next term
-        return (PI / 2.0) * Complex64::new(1.0, 0.0) + iterative_calculation(0.001, 1, 1, |n| {
-            k_n = k_n * ((2 * n - 1) as f64) / ((n * n * n) as f64);
-            k_n * x.powi(2 * n)
-        });
+    k_of_complementary_parameter((1.0 - x) * (1.0 + x))
+}
+
+/// Complete elliptic integral of the first kind K(m) as a function of the parameter m = k^2, with its branch cut along m > 1 taken as for k
+pub fn elliptic_k(m : Complex64) -> Complex64 {
+    k_of_complementary_parameter(1.0 - m)
+}
+
+/// Complementary complete elliptic integral of the first kind K'(k) = K(√(1 - k^2)) (DLMF 19.2.9).
+/// It has a branch cut where k^2 is real and negative, and takes the value K(1 - k^2 + i0) there.
+pub fn k_prime(x : Complex64) -> Complex64 {
+    k_of_complementary_parameter(x * x)
+}
+
+// K as a function of the complementary parameter 1 - m = 1 - k^2, which is passed directly so that it keeps its accuracy as k approaches 1.
+// The cut along m > 1 is where it is real and negative. Passing m itself gives K'(m) = K(1 - m) without rounding away small m.
+pub(super) fn k_of_complementary_parameter(complementary_m : Complex64) -> Complex64 {
+    // On the cut R_F takes the root of 1 - m - i0
+    let complementary_m = if complementary_m.im == 0.0 && complementary_m.re < 0.0 {
+        Complex64::new(complementary_m.re, -0.0)
     }
+    else {
+        complementary_m
+    };
 
-    panic!();
+    carlson_rf(Complex64::new(0.0, 0.0), complementary_m, Complex64::new(1.0, 0.0))
 }
 
 #[cfg(test)]
 mod tests {
     extern crate num;
 
-    // use super::k;
-    // use num::complex::{Complex64, ComplexFloat};
+    use std::f64::consts::PI;
+
+    use super::{k, k_prime, elliptic_k};
+    use num::complex::{Complex64, ComplexFloat};
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn complete_elliptic_k_five() {
+        // The value originally expected here is K at the parameter m = 5, whereas k takes the modulus, so that is k(√5) and elliptic_k(5)
+        let parameter_five = Complex64::new(0.7422062367111, 1.00945291);
+        assert!((k(5.0f64.sqrt().into()) - parameter_five).abs() < 0.001);
+        assert!((elliptic_k(5.0.into()) - parameter_five).abs() < 0.001);
 
-    // #[test]
-    // fn complete_elliptic_k_five() {
-    //     assert!((k(5.0.into()) - Complex64::new(0.7422062367111, 1.00945291)).abs() < 0.001);
-    // }
-}
\ No newline at end of file
+        // From mpmath, as ellipk(25 + i0), for the modulus 5
+        assert!((k(5.0.into()) - Complex64::new(0.3173735694908333, 0.6032224984955295)).abs() < 1e-14);
+        assert_eq!(k((-5.0).into()), k(5.0.into()));
+    }
+
+    #[test]
+    fn matches_reference_values() {
+        let references = [
+            (c(0.5, 0.0), c(1.685750354812596, 0.0), c(2.1565156474996434, 0.0)),
+            (c(0.999999, 0.0), c(7.947479773547967, 0.0), c(1.570797112193551, 0.0)),
+            (c(0.0, 2.0), c(1.0094529099892116, 0.0), c(0.7422062367111932, 1.0094529099892116)),
+            (c(1.5, 0.5), c(1.2129445018961686, 0.7979548602388986), c(1.220912409979885, -0.22115725422582114)),
+            (c(-0.3, -1.2), c(1.236292834225963, 0.10235454647327324), c(1.2059628817933934, -1.019502330755057)),
+        ];
+
+        for (x, k_value, k_prime_value) in references {
+            println!("k = {x}: {} against {k_value}, {} against {k_prime_value}", k(x), k_prime(x));
+            assert!((k(x) - k_value).abs() <= 1e-14 * k_value.abs());
+            assert!((k_prime(x) - k_prime_value).abs() <= 1e-14 * k_prime_value.abs());
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert!((k(c(0.0, 0.0)) - PI / 2.0).abs() <= 1e-15);
+        assert!(k(c(1.0, 0.0)).re.is_infinite());
+        // DLMF 19.6.1, K(1 / √2) = K'(1 / √2)
+        let x = c(0.5f64.sqrt(), 0.0);
+        assert!((k(x) - k_prime(x)).abs() <= 1e-14);
+
+        // DLMF 19.7.3, K(1 / k) = k (K(k) + i K'(k)) for 0 < k < 1, approaching the cut from above
+        for x in [0.2, 0.7, 0.95] {
+            let expected = x * (k(c(x, 0.0)) + c(0.0, 1.0) * k_prime(c(x, 0.0)));
+            assert!((k(c(1.0 / x, 0.0)) - expected).abs() <= 1e-14 * expected.abs());
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::f64::consts::PI;
+use num::complex::Complex64;
+
+use super::{carlson_rf, carlson_rd, carlson_rj, elliptic_k};
+
+const ZERO : Complex64 = Complex64::new(0.0, 0.0);
+const ONE : Complex64 = Complex64::new(1.0, 0.0);
+
+/// Complete elliptic integral of the second kind E(m) (DLMF 19.2.8) of the parameter m = k^2, from Carlson's symmetric integrals (DLMF 19.25.1).
+/// It has a branch cut along m > 1, and takes the value from above it there, like K.
+pub fn elliptic_e(m : Complex64) -> Complex64 {
+    // R_F and R_D both diverge where E(1) = 1 does not
+    if m == ONE {
+        return ONE;
+    }
+
+    let y = complementary(m, ONE);
+    carlson_rf(ZERO, y, ONE) - m / 3.0 * carlson_rd(ZERO, y, ONE)
+}
+
+/// Complete elliptic integral of the third kind Π(n, m) (DLMF 19.2.7) of the characteristic n = α^2 and the parameter m = k^2 (DLMF 19.25.2).
+/// For real n > 1 and real m < 1 it is the Cauchy principal value.
+pub fn elliptic_pi(n : Complex64, m : Complex64) -> Complex64 {
+    let y = complementary(m, ONE);
+    carlson_rf(ZERO, y, ONE) + n / 3.0 * carlson_rj(ZERO, y, ONE, 1.0 - n)
+}
+
+/// Incomplete elliptic integral of the first kind F(φ, m) (DLMF 19.2.4) of the amplitude φ and the parameter m = k^2 (DLMF 19.25.5),
+/// continued beyond |Re φ| <= π/2 by F(φ + jπ, m) = F(φ, m) + 2j K(m) (DLMF 19.2.10)
+pub fn elliptic_f(phi : Complex64, m : Complex64) -> Complex64 {
+    let (phi, periods) = reduce_amplitude(phi);
+    let (sine, cosine_squared, delta_squared) = legendre_arguments(phi, m);
+    let value = sine * carlson_rf(cosine_squared, delta_squared, ONE);
+    if periods == 0.0 { value } else { value + 2.0 * periods * elliptic_k(m) }
+}
+
+/// Incomplete elliptic integral of the second kind E(φ, m) (DLMF 19.2.5) of the amplitude φ and the parameter m = k^2 (DLMF 19.25.9),
+/// continued beyond |Re φ| <= π/2 by E(φ + jπ, m) = E(φ, m) + 2j E(m) (DLMF 19.2.10)
+pub fn elliptic_e_incomplete(phi : Complex64, m : Complex64) -> Complex64 {
+    let (phi, periods) = reduce_amplitude(phi);
+    let (sine, cosine_squared, delta_squared) = legendre_arguments(phi, m);
+    let value = sine * carlson_rf(cosine_squared, delta_squared, ONE)
+        - m / 3.0 * sine * sine * sine * carlson_rd(cosine_squared, delta_squared, ONE);
+    if periods == 0.0 { value } else { value + 2.0 * periods * elliptic_e(m) }
+}
+
+/// Incomplete elliptic integral of the third kind Π(n; φ, m) (DLMF 19.2.7) of the characteristic n = α^2, the amplitude φ and the parameter m = k^2 (DLMF 19.25.14),
+/// continued beyond |Re φ| <= π/2 by Π(n; φ + jπ, m) = Π(n; φ, m) + 2j Π(n, m).
+/// For real n > 1, real φ and real m < 1 it is the Cauchy principal value where n sin^2 φ > 1.
+pub fn elliptic_pi_incomplete(n : Complex64, phi : Complex64, m : Complex64) -> Complex64 {
+    let (phi, periods) = reduce_amplitude(phi);
+    let (sine, cosine_squared, delta_squared) = legendre_arguments(phi, m);
+    let p = 1.0 - n * sine * sine;
+    let value = sine * carlson_rf(cosine_squared, delta_squared, ONE)
+        + n / 3.0 * sine * sine * sine * carlson_rj(cosine_squared, delta_squared, ONE, p);
+    if periods == 0.0 { value } else { value + 2.0 * periods * elliptic_pi(n, m) }
+}
+
+/// Complete elliptic integral of the second kind E(k) of the modulus k, as `k` takes it, with E'(k) = E(√(1 - k^2)) as `elliptic_e(1 - k^2)`
+pub fn elliptic_e_modulus(x : Complex64) -> Complex64 {
+    elliptic_e(x * x)
+}
+
+/// Complete elliptic integral of the third kind Π(α^2, k) of the modulus k
+pub fn elliptic_pi_modulus(n : Complex64, x : Complex64) -> Complex64 {
+    elliptic_pi(n, x * x)
+}
+
+/// Incomplete elliptic integral of the first kind F(φ, k) of the modulus k
+pub fn elliptic_f_modulus(phi : Complex64, x : Complex64) -> Complex64 {
+    elliptic_f(phi, x * x)
+}
+
+/// Incomplete elliptic integral of the second kind E(φ, k) of the modulus k
+pub fn elliptic_e_incomplete_modulus(phi : Complex64, x : Complex64) -> Complex64 {
+    elliptic_e_incomplete(phi, x * x)
+}
+
+/// Incomplete elliptic integral of the third kind Π(φ, α^2, k) of the modulus k
+pub fn elliptic_pi_incomplete_modulus(n : Complex64, phi : Complex64, x : Complex64) -> Complex64 {
+    elliptic_pi_incomplete(n, phi, x * x)
+}
+
+// 1 - m w, put on the lower side of the negative real axis when it lands on it,
+// so that real m > 1 takes the value from above its cut as K does
+fn complementary(m : Complex64, w : Complex64) -> Complex64 {
+    let value = 1.0 - m * w;
+    if value.im == 0.0 && value.re < 0.0 { Complex64::new(value.re, -0.0) } else { value }
+}
+
+// φ less the nearest multiple jπ, and j, so that the Carlson forms are used where they hold
+fn reduce_amplitude(phi : Complex64) -> (Complex64, f64) {
+    let periods = (phi.re / PI).round();
+    (phi - periods * PI, periods)
+}
+
+// sin φ, cos^2 φ and Δ^2 = 1 - m sin^2 φ, which the Legendre forms pass to the Carlson ones
+fn legendre_arguments(phi : Complex64, m : Complex64) -> (Complex64, Complex64, Complex64) {
+    let sine = phi.sin();
+    let cosine = phi.cos();
+    (sine, cosine * cosine, complementary(m, sine * sine))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{elliptic_e, elliptic_pi, elliptic_f, elliptic_e_incomplete, elliptic_pi_incomplete};
+    use super::{elliptic_e_modulus, elliptic_f_modulus, elliptic_pi_incomplete_modulus};
+    use crate::special_functions::{k, k_prime, elliptic_k};
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_complete_reference_values() {
+        // From mpmath, as m and E(m), taking ellipe(m + i0) on the cut
+        let references = [
+            (c(0.3, 0.0), c(1.4453630644126654, 0.0)),
+            (c(-2.0, 0.0), c(2.184438142746201, 0.0)),
+            (c(5.0, 0.0), c(0.3607586639379028, -1.6257306716064184)),
+            (c(0.5, 1.5), c(1.541278731531933, -0.5965391492958321)),
+            (c(-1.0, -3.0), c(2.1031825291802035, 0.7914682380748732)),
+        ];
+
+        for (m, expected) in references {
+            println!("m = {m}: {} against {expected}", elliptic_e(m));
+            assert!((elliptic_e(m) - expected).norm() <= 1e-14 * expected.norm());
+        }
+
+        // From mpmath, as n, m and Π(n, m), with the real part of ellippi(n, m) as the principal value for n > 1
+        let references = [
+            (c(0.4, 0.0), c(0.3, 0.0), c(2.2378543724020243, 0.0)),
+            (c(-2.0, 0.0), c(0.8, 0.0), c(1.1768744170553485, 0.0)),
+            (c(0.5, 1.0), c(0.2, -0.4), c(1.4245959196186635, 0.6898455400665683)),
+            (c(-1.0, 0.5), c(3.0, 0.0), c(0.7281544292809073, 0.7692414498602878)),
+            (c(2.0, 0.0), c(0.5, 0.0), c(-0.31354468346518405, 0.0)),
+            (c(4.0, 0.0), c(-1.0, 0.0), c(0.12707374324307386, 0.0)),
+        ];
+
+        for (n, m, expected) in references {
+            println!("n = {n}, m = {m}: {} against {expected}", elliptic_pi(n, m));
+            assert!((elliptic_pi(n, m) - expected).norm() <= 1e-14 * expected.norm());
+        }
+    }
+
+    #[test]
+    fn matches_incomplete_reference_values() {
+        // From mpmath, as φ, m, F(φ, m) and E(φ, m), taking m + i0 on the cut
+        let references = [
+            (c(0.7, 0.0), c(0.4, 0.0), c(0.7225053638669685, 0.0), c(0.6787053560033744, 0.0)),
+            (c(2.0, 0.0), c(0.5, 0.0), c(2.444382636061119, 0.0), c(1.6628951029536017, 0.0)),
+            (c(4.0, 0.0), c(0.9, 0.0), c(6.126351578834841, 0.0), c(2.9777552454757776, 0.0)),
+            (c(1.2, 0.0), c(3.0, 0.0), c(1.0010773804561062, 0.8995697452073659), c(0.4752239353510171, -0.5067312223233146)),
+            (c(-1.0, 0.0), c(-3.0, 0.0), c(-0.7807065662256887, 0.0), c(-1.3256631975799982, 0.0)),
+            (c(1.0, 2.0), c(0.3, 0.4), c(0.5826472828791899, 1.5096723995003527), c(1.9989280299561314, 2.643138395422948)),
+            (c(-0.5, 0.7), c(2.0, -1.0), c(-0.3505766722583236, 0.6402000593266103), c(-0.7112208553199633, 0.7356118894284032)),
+        ];
+
+        for (phi, m, f_value, e_value) in references {
+            println!("φ = {phi}, m = {m}: {} against {f_value}, {} against {e_value}", elliptic_f(phi, m), elliptic_e_incomplete(phi, m));
+            assert!((elliptic_f(phi, m) - f_value).norm() <= 1e-14 * f_value.norm());
+            assert!((elliptic_e_incomplete(phi, m) - e_value).norm() <= 1e-14 * e_value.norm());
+        }
+
+        // From mpmath, as n, φ, m and Π(n; φ, m), with the real part of ellippi(n, φ, m) as the principal value for n sin^2 φ > 1
+        let references = [
+            (c(0.3, 0.0), c(0.7, 0.0), c(0.4, 0.0), c(0.7581684307987496, 0.0)),
+            (c(-1.5, 0.0), c(2.5, 0.0), c(0.2, 0.0), c(1.5176698376171691, 0.0)),
+            (c(0.5, 0.5), c(0.8, -0.3), c(0.6, 0.1), c(1.0432401863971652, -0.3886211772068194)),
+            (c(0.6, 0.0), c(4.0, 0.0), c(0.5, 0.0), c(7.1655199147207895, 0.0)),
+            (c(2.0, 0.0), c(1.0, 0.0), c(0.5, 0.0), c(0.7045837467687983, 0.0)),
+            (c(1.5, 0.0), c(1.2, 0.0), c(0.3, 0.0), c(0.8219515859129786, 0.0)),
+            (c(4.0, 0.0), c(-0.9, 0.0), c(-1.0, 0.0), c(-0.3382977603251725, 0.0)),
+            (c(3.0, 0.0), c(1.5, 0.0), c(0.9, 0.0), c(-0.5452815782578411, 0.0)),
+        ];
+
+        for (n, phi, m, expected) in references {
+            println!("n = {n}, φ = {phi}, m = {m}: {} against {expected}", elliptic_pi_incomplete(n, phi, m));
+            assert!((elliptic_pi_incomplete(n, phi, m) - expected).norm() <= 1e-14 * expected.norm());
+        }
+    }
+
+    #[test]
+    fn legendre_relation() {
+        // DLMF 19.7.1, E K' + E' K - K K' = π / 2 in the modulus convention of k
+        for x in [c(0.1, 0.0), c(0.5, 0.0), c(0.9, 0.0), c(0.999, 0.0), c(0.4, 0.3), c(0.8, -0.5)] {
+            let complementary = 1.0 - x * x;
+            let lhs = elliptic_e_modulus(x) * k_prime(x) + elliptic_e(complementary) * k(x) - k(x) * k_prime(x);
+            println!("k = {x}: {lhs}");
+            assert!((lhs - PI / 2.0).norm() <= 1e-14 * k(x).norm() * k_prime(x).norm());
+        }
+    }
+
+    #[test]
+    fn conventions_and_special_cases() {
+        let (phi, x, n) = (c(0.9, 0.2), c(0.6, 0.1), c(0.3, -0.2));
+        assert_eq!(elliptic_f_modulus(phi, x), elliptic_f(phi, x * x));
+        assert_eq!(elliptic_pi_incomplete_modulus(n, phi, x), elliptic_pi_incomplete(n, phi, x * x));
+        assert_eq!(elliptic_e_modulus(c(-2.0, 0.0)), elliptic_e_modulus(c(2.0, 0.0)));
+
+        for m in [c(0.2, 0.0), c(-4.0, 0.0), c(0.5, 0.5)] {
+            // The incomplete integrals at φ = π / 2 are the complete ones, and Π(0; φ, m) = F(φ, m)
+            let half_pi = c(PI / 2.0, 0.0);
+            assert!((elliptic_f(half_pi, m) - elliptic_k(m)).norm() <= 1e-14 * elliptic_k(m).norm());
+            assert!((elliptic_e_incomplete(half_pi, m) - elliptic_e(m)).norm() <= 1e-14 * elliptic_e(m).norm());
+            assert!((elliptic_pi_incomplete(n, half_pi, m) - elliptic_pi(n, m)).norm() <= 1e-14 * elliptic_pi(n, m).norm());
+            assert!((elliptic_pi_incomplete(c(0.0, 0.0), phi, m) - elliptic_f(phi, m)).norm() <= 1e-14 * elliptic_f(phi, m).norm());
+        }
+
+        assert!((elliptic_e(c(0.0, 0.0)) - PI / 2.0).norm() <= 1e-15);
+        assert_eq!(elliptic_e(c(1.0, 0.0)), c(1.0, 0.0));
+        assert_eq!(elliptic_f(c(0.0, 0.0), c(0.7, 0.0)), c(0.0, 0.0));
+    }
+}
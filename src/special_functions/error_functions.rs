@@ -0,0 +1,292 @@
+use std::f64::consts::{FRAC_2_SQRT_PI, PI, SQRT_2};
+
+use num::complex::Complex64;
+
+use crate::iterative_calculation;
+
+// Below this the power series of erf is used rather than 1 - erfc, which would cancel
+const ERF_SERIES_RADIUS : f64 = 0.5;
+
+// The regions of the Faddeeva algorithm are bounded by ellipses with these semi-axes in x and y
+const ELLIPSE_X : f64 = 6.3;
+const ELLIPSE_Y : f64 = 4.4;
+// The square of the scaled radius within which the power series is used
+const POWER_SERIES_RADIUS_SQUARED : f64 = 0.085264;
+// The band about the real axis in which w is expanded in powers of iy. Beyond its end e^(-x^2) no longer contributes to Re w.
+const NEAR_AXIS_HEIGHT : f64 = 0.1;
+const NEAR_AXIS_WIDTH : f64 = 12.0;
+const NEAR_AXIS_MAX_TERMS : usize = 40;
+
+/// The error function erf(x) (DLMF 7.2.1)
+pub fn erf(x : f64) -> f64 {
+    if x == 0.0 {
+        return x;
+    }
+
+    if x.abs() < ERF_SERIES_RADIUS {
+        // DLMF 7.6.1
+        let x_squared = x * x;
+        let mut power = x;
+        let sum = iterative_calculation(f64::EPSILON, 0, 1, |n| {
+            if n > 0 {
+                power *= -x_squared / n as f64;
+            }
+            power / (2 * n + 1) as f64
+        });
+        return FRAC_2_SQRT_PI * sum;
+    }
+
+    1.0 - erfc(x)
+}
+
+/// The complementary error function erfc(x) = 1 - erf(x) (DLMF 7.2.2), which keeps its relative accuracy as it decays for large x
+pub fn erfc(x : f64) -> f64 {
+    if x < 0.0 {
+        // DLMF 7.4.2
+        return 2.0 - erfc(-x);
+    }
+
+    if x < ERF_SERIES_RADIUS {
+        return 1.0 - erf(x);
+    }
+
+    (-x * x).exp() * erfcx(x)
+}
+
+/// The scaled complementary error function erfcx(x) = e^(x^2) erfc(x), which stays finite as erfc underflows, from erfcx(x) = w(ix) (DLMF 7.2.3)
+pub fn erfcx(x : f64) -> f64 {
+    faddeeva(Complex64::new(0.0, x)).re
+}
+
+/// The imaginary error function erfi(x) = -i erf(ix) (DLMF 7.2.1), from Im w(x) = e^(-x^2) erfi(x)
+pub fn erfi(x : f64) -> f64 {
+    if x == 0.0 {
+        return x;
+    }
+
+    if x.abs() < ERF_SERIES_RADIUS {
+        // DLMF 7.6.1 with its alternating signs removed
+        let x_squared = x * x;
+        let mut power = x;
+        let sum = iterative_calculation(f64::EPSILON, 0, 1, |n| {
+            if n > 0 {
+                power *= x_squared / n as f64;
+            }
+            power / (2 * n + 1) as f64
+        });
+        return FRAC_2_SQRT_PI * sum;
+    }
+
+    (x * x).exp() * faddeeva(Complex64::new(x, 0.0)).im
+}
+
+/// Dawson's integral F(x) = e^(-x^2) times the integral of e^(t^2) from 0 to x (DLMF 7.2.5), from Im w(x) = 2 F(x) / sqrt(π) (DLMF 7.5.1)
+pub fn dawson(x : f64) -> f64 {
+    faddeeva(Complex64::new(x, 0.0)).im / FRAC_2_SQRT_PI
+}
+
+/// The Voigt profile, the convolution of a normal distribution of standard deviation σ with a Lorentzian of half width γ,
+/// as Re w((x + iγ) / (σ sqrt(2))) / (σ sqrt(2π)), normalised to unit area
+pub fn voigt(x : f64, sigma : f64, gamma : f64) -> f64 {
+    if sigma == 0.0 {
+        return gamma / (PI * (x * x + gamma * gamma));
+    }
+
+    let z = Complex64::new(x, gamma) / (sigma * SQRT_2);
+    faddeeva(z).re / (sigma * (2.0 * PI).sqrt())
+}
+
+/// The Faddeeva function w(z) = e^(-z^2) erfc(-iz) (DLMF 7.2.3), by the algorithm of Poppe and Wijers (ACM TOMS 680).
+/// In the first quadrant this is a power series near the origin, Gautschi's Taylor expansion about z + ih within an ellipse,
+/// and the Laplace continued fraction (DLMF 7.9.3) beyond it. Close to the real axis, where those only resolve Re w relative to |w|,
+/// it is instead a Taylor series in iy about the real axis, so that the Voigt profile keeps its relative accuracy in the wings.
+/// The other quadrants follow from w(-conj z) = conj w(z) and w(-z) = 2 e^(-z^2) - w(z) (DLMF 7.4.3),
+/// so in the lower half plane it grows like e^(-z^2) and overflows when Im z^2 is large.
+pub fn faddeeva(z : Complex64) -> Complex64 {
+    let x = z.re.abs();
+    let y = z.im.abs();
+    let quadrant_value = if y < NEAR_AXIS_HEIGHT && x < NEAR_AXIS_WIDTH {
+        near_real_axis(x, y)
+    }
+    else {
+        first_quadrant(x, y)
+    };
+
+    let value = if z.im < 0.0 {
+        2.0 * (-Complex64::new(x, -y).powi(2)).exp() - quadrant_value.conj()
+    }
+    else {
+        quadrant_value
+    };
+
+    if z.re < 0.0 { value.conj() } else { value }
+}
+
+// The algorithm of Poppe and Wijers for x, y >= 0
+fn first_quadrant(x : f64, y : f64) -> Complex64 {
+    let scaled_x = x / ELLIPSE_X;
+    let scaled_y = y / ELLIPSE_Y;
+    let rho_squared = scaled_x * scaled_x + scaled_y * scaled_y;
+    if rho_squared < POWER_SERIES_RADIUS_SQUARED {
+        return power_series(Complex64::new(x, y), scaled_y, rho_squared);
+    }
+
+    let mut value = taylor_continued_fraction(x, y, scaled_y, rho_squared);
+    if y == 0.0 {
+        // On the real axis the real part is known exactly
+        value.re = (-x * x).exp();
+    }
+    value
+}
+
+// w(x + iy) = sum_k (iy)^k w^(k)(x) / k!, where differentiating w' = -2z w + 2i / sqrt(π) (DLMF 7.10.2) gives w^(k+1) = -2z w^(k) - 2k w^(k-1).
+// The real parts of the derivatives are e^(-x^2) times Hermite polynomials, so the real part of the sum is as accurate as its leading terms.
+fn near_real_axis(x : f64, y : f64) -> Complex64 {
+    let mut previous = first_quadrant(x, 0.0);
+    let mut current = -2.0 * x * previous + Complex64::new(0.0, FRAC_2_SQRT_PI);
+    let mut factor = Complex64::new(0.0, y);
+    let mut sum = previous + factor * current;
+    for k in 1..NEAR_AXIS_MAX_TERMS {
+        let next = -2.0 * x * current - 2.0 * k as f64 * previous;
+        previous = current;
+        current = next;
+        factor *= Complex64::new(0.0, y) / (k + 1) as f64;
+        let term = factor * current;
+        sum += term;
+        if term.re.abs() <= f64::EPSILON * sum.re.abs() && term.norm() <= f64::EPSILON * sum.norm() {
+            break;
+        }
+    }
+
+    sum
+}
+
+// w(z) = e^(-z^2) (1 + erf(iz)) = e^(-z^2) (1 + 2iz / sqrt(π) sum_n z^2n / (n! (2n + 1))) from DLMF 7.6.1, with the sum in Horner's form
+fn power_series(z : Complex64, scaled_y : f64, rho_squared : f64) -> Complex64 {
+    let terms = (6.0 + 72.0 * (1.0 - 0.85 * scaled_y) * rho_squared.sqrt()).round() as usize;
+    let z_squared = z * z;
+    let mut sum = Complex64::new(1.0 / (2 * terms + 1) as f64, 0.0);
+    for n in (1..=terms).rev() {
+        sum = sum * z_squared / n as f64 + 1.0 / (2 * n - 1) as f64;
+    }
+
+    (-z_squared).exp() * (1.0 + Complex64::new(0.0, FRAC_2_SQRT_PI) * z * sum)
+}
+
+// Gautschi's method in the first quadrant. The continued fraction is summed from its tail for the convergents r_n, which within the ellipse
+// are combined with powers of 2h into the truncated Taylor series of w about z + ih. The step h shrinks to zero at the ellipse, where only the continued fraction remains.
+fn taylor_continued_fraction(x : f64, y : f64, scaled_y : f64, rho_squared : f64) -> Complex64 {
+    let (h, taylor_terms, fraction_terms) = if rho_squared > 1.0 {
+        (0.0, 0, (3.0 + 1442.0 / (26.0 * rho_squared.sqrt() + 77.0)) as usize)
+    }
+    else {
+        let s = (1.0 - scaled_y) * (1.0 - rho_squared).sqrt();
+        (1.88 * s, (7.0 + 34.0 * s).round() as usize, (16.0 + 26.0 * s).round() as usize)
+    };
+
+    let mut r = Complex64::new(0.0, 0.0);
+    let mut sum = Complex64::new(0.0, 0.0);
+    let mut lambda = if h > 0.0 { (2.0 * h).powi(taylor_terms as i32) } else { 0.0 };
+    for n in (0..=fraction_terms).rev() {
+        let n_plus_one = (n + 1) as f64;
+        let t = Complex64::new(y + h + n_plus_one * r.re, x - n_plus_one * r.im);
+        r = 0.5 * t / t.norm_sqr();
+        if h > 0.0 && n <= taylor_terms {
+            sum = r * (lambda + sum);
+            lambda /= 2.0 * h;
+        }
+    }
+
+    FRAC_2_SQRT_PI * if h > 0.0 { sum } else { r }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+    use super::{erf, erfc, erfcx, erfi, dawson, voigt, faddeeva};
+    use crate::special_functions::test_support::c;
+
+    fn assert_close(value : f64, expected : f64, tolerance : f64) {
+        assert!((value - expected).abs() <= tolerance * expected.abs(), "{value} against {expected}");
+    }
+
+    #[test]
+    fn real_functions_match_reference_values() {
+        let references = [
+            (-3.0, -0.9999779095030014, 1.9999779095030015, 16205.988853999586, -1629.9946226015657, -0.1782710306105583),
+            (-0.3, -0.3286267594591274, 1.3286267594591274, 1.4537492328427655, -0.3489493387589362, -0.28263166502131193),
+            (1e-5, 1.1283791670579e-5, 0.9999887162083294, 0.9999887163083283, 1.1283791671331254e-05, 9.999999999333333e-6),
+            (0.2, 0.22270258921047847, 0.7772974107895215, 0.8090195199015807, 0.2287212992439713, 0.19475103336802807),
+            (0.7, 0.6778011938374184, 0.32219880616258156, 0.525930337349441, 0.9402829338335074, 0.5105040575592318),
+            (2.0, 0.9953222650189527, 0.004677734981047266, 0.25539567631050575, 18.564802414575553, 0.30134038892379195),
+            (5.5, 0.9999999999999927, 7.357847917974398e-15, 0.10096221839949909, 1432099172039.8328, 0.09249323231075476),
+            (12.0, 1.0, 1.3562611692059042e-64, 0.04685422101489376, 1.6299357995243493e+61, 0.04181287645398826),
+        ];
+
+        for (x, erf_x, erfc_x, erfcx_x, erfi_x, dawson_x) in references {
+            println!("x = {x}: {} {} {} {} {}", erf(x), erfc(x), erfcx(x), erfi(x), dawson(x));
+            assert_close(erf(x), erf_x, 1e-15);
+            assert_close(erfc(x), erfc_x, 1e-13);
+            assert_close(erfcx(x), erfcx_x, 1e-13);
+            assert_close(erfi(x), erfi_x, 1e-13);
+            assert_close(dawson(x), dawson_x, 1e-13);
+        }
+
+        assert_eq!(erfc(30.0), 0.0);
+        assert_close(erfcx(30.0), 0.01879588886141675, 1e-14);
+        assert_eq!(erfi(30.0), f64::INFINITY);
+        assert_eq!(erf(0.0), 0.0);
+    }
+
+    #[test]
+    fn faddeeva_matches_reference_values() {
+        let references = [
+            (c(0.5, 0.5), c(0.533156707912175, 0.2304882313844584)),
+            (c(-2.0, 1.0), c(0.14023958136627795, -0.2222134401798991)),
+            (c(3.0, -0.4), c(-0.03049233741353159, 0.19592773374362668)),
+            (c(-10.0, -2.0), c(-0.011001556705733516, -0.05447181709865651)),
+            (c(0.0, 30.0), c(0.01879588886141675, 0.0)),
+            (c(100.0, 0.001), c(5.64274233093359e-8, 0.005642177972029779)),
+            (c(0.001, -0.001), c(1.0011283776605888, 0.0011303806715998639)),
+            (c(5.2, 1e-7), c(2.216430785964255e-9, 0.11062744390776726)),
+        ];
+
+        for (z, expected) in references {
+            let value = faddeeva(z);
+            println!("w({z}) = {value} against {expected}");
+            assert!((value - expected).norm() <= 1e-13 * expected.norm());
+            // Near the real axis the small real part keeps its own relative accuracy
+            assert_close(value.re, expected.re, 1e-12);
+        }
+        assert_eq!(faddeeva(c(0.0, 0.0)), c(1.0, 0.0));
+    }
+
+    #[test]
+    fn faddeeva_satisfies_its_differential_equation() {
+        // w'(z) = -2z w(z) + 2i / sqrt(π) (DLMF 7.10.2), by central differences across the boundaries between methods
+        let step = 1e-5;
+        for z in [c(1.5, 0.09), c(1.5, 0.11), c(1.8, 0.2), c(6.2, 0.5), c(6.4, 0.5), c(11.9, 0.05), c(12.1, 0.05), c(-3.0, -1.0)] {
+            let derivative = (faddeeva(z + step) - faddeeva(z - step)) / (2.0 * step);
+            let expected = -2.0 * z * faddeeva(z) + c(0.0, FRAC_2_SQRT_PI);
+            assert!((derivative - expected).norm() <= 1e-8 * expected.norm(), "{z}: {derivative} against {expected}");
+        }
+    }
+
+    #[test]
+    fn voigt_matches_reference_values() {
+        let references = [
+            (0.0, 1.0, 1.0, 0.2087092805203677),
+            (2.0, 0.5, 0.1, 0.01052664750674449),
+            (-7.0, 1.0, 0.0001, 6.941713113507969e-7),
+            (40.0, 1.0, 0.3, 5.979198261881248e-5),
+            (1.0, 2.0, 0.0, 0.17603266338214973),
+        ];
+
+        for (x, sigma, gamma, expected) in references {
+            assert_close(voigt(x, sigma, gamma), expected, 1e-13);
+        }
+        // The Lorentzian limit
+        assert_close(voigt(0.5, 0.0, 2.0), 2.0 / (PI * 4.25), 1e-15);
+    }
+}
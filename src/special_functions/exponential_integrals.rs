@@ -1,72 +1,202 @@
+use std::f64::consts::PI;
 use num::complex::{Complex64, ComplexFloat};
 
-const EGAMMA : f64 = 0.577215664901532860606512090082402431_f64;
-    
-const EIN_TERM_COUNT : usize = 11;
-const EIN_NUMERATOR: [f64; EIN_TERM_COUNT] = [
-    0.10000000000000000000e1,
-    0.20502084567791706628e0,
-    0.39390751931629655245e-1,
-    0.34858236552923791179e-2,
-    0.29317755061426648946e-3,
-    0.13754735702992239386e-4,
-    0.60964461747745580030e-6,
-    0.14447186550089174805e-7,
-    0.30430043273133224684e-9,
-    0.22059389087476526250e-11,
-    0.49848280581687288340e-14,
-];
-const EIN_DENOMINATOR: [f64; EIN_TERM_COUNT] = [
-    0.10000000000000000000e1,
-    0.45502084567791806628e0,
-    0.97590407795553366258e-1,
-    0.13021156399851994778e-1,
-    0.11999111377470476100e-2,
-    0.80015095592166145984e-4,
-    0.39222830738857592254e-5,
-    0.14003621189603245150e-6,
-    0.55465894537386945817e-10,
-    0.42591339012402143020e-12,
-    0.0,
-];
-
-const E1_TERM_COUNT: usize = 11;
-const E1_NUMERATOR: [i32; E1_TERM_COUNT] = [
-    1,
-    109,
-    4842,
-    114064, 
-    1553663, 
-    12518100, 
-    58603440, 
-    150023520, 
-    184386240, 
-    80627040, 
-    3628800,
-];
-const E1_DENOMINATOR: [i32; E1_TERM_COUNT] = [
-    1,
-    110,
-    4950,
-    118800,
-    1663200,
-    13970880,
-    69854400,
-    199584000,
-    299376000,
-    199584000,
-    39916800,
-];
-
-/// Exponential integral (DLMF 6.2.3)
-pub fn ein(input: Complex64) -> Complex64  {
-    if input.abs() < 4.0 {
-        let numerator : Complex64 = EIN_NUMERATOR.iter().enumerate().map(|(pos, x)| x * input.powi(pos as i32)).sum();
-        let denominator : Complex64 = EIN_DENOMINATOR.iter().enumerate().map(|(pos, x)| x * input.powi(pos as i32)).sum();
-        return input * numerator / denominator;
+use crate::{iterative_calculation, EM_GAMMA};
+use super::{upper_gamma, to_complex64, from_complex64};
+
+// Within this Ein is summed from its power series, beyond which the terms grow before they shrink
+const EIN_SERIES_RADIUS : f64 = 2.0;
+
+/// Complementary exponential integral Ein (DLMF 6.2.3), an entire function
+pub fn ein<TNum : ComplexFloat>(z : TNum) -> TNum {
+    from_complex64(complex_ein(to_complex64(z)))
+}
+
+/// Exponential integral E1 (DLMF 6.2.1), with its branch cut along the negative real axis.
+/// Real types give NaN for negative arguments, where the value is complex.
+pub fn e1<TNum : ComplexFloat>(z : TNum) -> TNum {
+    from_complex64(complex_e_p(Complex64::new(1.0, 0.0), to_complex64(z)))
+}
+
+/// Generalized exponential integral E_n (DLMF 8.19.2) for integer n, with the branch cut of E1
+pub fn e_n<TNum : ComplexFloat>(n : i32, z : TNum) -> TNum {
+    from_complex64(complex_e_p(Complex64::new(n as f64, 0.0), to_complex64(z)))
+}
+
+/// Generalized exponential integral E_p (DLMF 8.19.1), on the principal branch of z^(p - 1) Γ(1 - p, z)
+pub fn e_p<TNum : ComplexFloat>(p : TNum, z : TNum) -> TNum {
+    from_complex64(complex_e_p(to_complex64(p), to_complex64(z)))
+}
+
+/// Exponential integral Ei (DLMF 6.2.5), taken as its principal value on the whole real axis.
+/// Off it Ei(z) = -E1(-z) ± iπ in the upper and lower half planes, continuing DLMF 6.2.6 and 6.2.7, so that the real axis is a branch cut for positive x.
+pub fn ei<TNum : ComplexFloat>(z : TNum) -> TNum {
+    from_complex64(complex_ei(to_complex64(z)))
+}
+
+/// Logarithmic integral li (DLMF 6.2.8) as Ei(ln z), with its branch cut along the negative real axis.
+/// Real types give NaN for negative arguments, where the value is complex.
+pub fn li<TNum : ComplexFloat>(z : TNum) -> TNum {
+    let z = to_complex64(z);
+    if z == Complex64::new(0.0, 0.0) {
+        return from_complex64(z);
+    }
+
+    from_complex64(complex_ei(z.ln()))
+}
+
+fn complex_ein(z : Complex64) -> Complex64 {
+    // Ein(z) = z - z^2 / 4 + ..., where the series terms would be too small for the convergence test
+    if z.norm() < f64::EPSILON {
+        return z;
+    }
+
+    if z.norm() < EIN_SERIES_RADIUS {
+        // DLMF 6.6.4, Ein(z) = sum_(k >= 1) (-1)^(k + 1) z^k / (k k!)
+        let mut power = Complex64::new(-1.0, 0.0);
+        return iterative_calculation(f64::EPSILON, 1, 1, |k| {
+            power *= -z / k as f64;
+            power / k as f64
+        });
+    }
+
+    // DLMF 6.2.4
+    complex_e_p(Complex64::new(1.0, 0.0), z) + z.ln() + EM_GAMMA
+}
+
+fn complex_e_p(p : Complex64, z : Complex64) -> Complex64 {
+    if z == Complex64::new(0.0, 0.0) {
+        // DLMF 8.19.6, with the integral diverging for Re p <= 1
+        return if p.re > 1.0 { 1.0 / (p - 1.0) } else { Complex64::new(f64::INFINITY, 0.0) };
+    }
+
+    ((p - 1.0) * z.ln()).exp() * upper_gamma(1.0 - p, z)
+}
+
+fn complex_ei(z : Complex64) -> Complex64 {
+    let minus_e1 = -complex_e_p(Complex64::new(1.0, 0.0), -z);
+    if z.im > 0.0 {
+        minus_e1 + Complex64::new(0.0, PI)
+    }
+    else if z.im < 0.0 {
+        minus_e1 - Complex64::new(0.0, PI)
+    }
+    else {
+        // The average of the values either side of the cut of E1(-x) for positive x (DLMF 6.2.6), and -E1(-x) itself for negative x
+        Complex64::new(minus_e1.re, 0.0)
     }
+}
 
-    let numerator : Complex64 = E1_NUMERATOR.iter().enumerate().map(|(pos, x)| *x as f64 * input.powi((E1_TERM_COUNT - 1 - pos) as i32)).sum();
-    let denominator : Complex64 = E1_DENOMINATOR.iter().enumerate().map(|(pos, x)| *x as f64 * input.powi((E1_TERM_COUNT - 1 - pos) as i32)).sum();
-    return (-input).exp() * input.powi(-1) * numerator / denominator + input.ln() + EGAMMA;
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{ein, e1, e_n, e_p, ei, li};
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_reference_values() {
+        let references = [
+            (c(0.5, 0.0), c(0.5597735947761608, 0.0), c(0.44384207911774837, 0.0)),
+            (c(3.0, 0.0), c(0.013048381094197037, 0.0), c(1.6888763346638396, 0.0)),
+            (c(-2.5, 0.0), c(-7.0737658945786, -PI), c(-5.5802594978029125, 0.0)),
+            (c(1.0, 2.0), c(-0.1267842855915597, -0.03508158292818701), c(1.2551503355270233, 1.0720671348659034)),
+            (c(-4.0, -0.5), c(-18.369991759633486, -3.507105957846211), c(-16.39872964034408, -6.524343616889243)),
+            (c(30.0, 10.0), c(-1.814478073838587e-15, 2.2299884151733475e-15), c(4.031093304392599, 0.3217505543966444)),
+        ];
+
+        for (z, e1_value, ein_value) in references {
+            println!("z = {z}: {} against {e1_value}, {} against {ein_value}", e1(z), ein(z));
+            assert!((e1(z) - e1_value).norm() <= 1e-14 * e1_value.norm());
+            assert!((ein(z) - ein_value).norm() <= 1e-14 * ein_value.norm());
+        }
+
+        let references = [
+            (c(2.0, 0.0), c(0.7, 0.0), c(0.23494711352795314, 0.0)),
+            (c(5.0, 0.0), c(12.0, -3.0), c(-3.6093418386559035e-07, -1.38463098987675e-08)),
+            (c(0.5, 0.0), c(2.0, 0.0), c(0.05702612399289205, 0.0)),
+            (c(-1.5, 0.5), c(1.0, 1.0), c(-0.35777251502142593, -0.2212936651379834)),
+            (c(3.0, 0.0), c(-2.0, 0.5), c(-1.3519883774368249, -4.70727102455032)),
+        ];
+
+        for (p, z, expected) in references {
+            println!("p = {p}, z = {z}: {} against {expected}", e_p(p, z));
+            assert!((e_p(p, z) - expected).norm() <= 1e-14 * expected.norm());
+        }
+    }
+
+    #[test]
+    fn matches_ei_and_li_reference_values() {
+        let references : [(f64, f64); 5] = [
+            (0.1, -1.6228128139692766),
+            (1.0, 1.8951178163559368),
+            (5.0, 40.18527535580318),
+            (-3.0, -0.013048381094197037),
+            (40.0, 6039718263611242.0),
+        ];
+
+        for (x, expected) in references {
+            assert!((ei(x) - expected).abs() <= 1e-14 * expected.abs(), "Ei({x}) = {} against {expected}", ei(x));
+        }
+
+        let references : [(f64, f64); 4] = [
+            (0.5, -0.37867104306108795),
+            (2.0, 1.045163780117493),
+            (10.0, 6.165599504787298),
+            (1000000.0, 78627.54915946219),
+        ];
+
+        for (x, expected) in references {
+            assert!((li(x) - expected).abs() <= 1e-14 * expected.abs(), "li({x}) = {} against {expected}", li(x));
+        }
+
+        let references = [
+            (c(1.0, 1.0), c(1.764625985563854, 2.3877698515105226), c(0.6139116692211956, 2.0595842141925775)),
+            (c(-1.0, -2.0), c(0.1267842855915597, -3.106511070661606), c(0.8718363809259672, -3.5600394173685452)),
+            (c(3.0, -0.5), c(9.383603509330943, -3.270805623674423), c(2.197156431985103, -0.44989885493726883)),
+            (c(-2.0, 0.0), c(-0.04890051070806112, 0.0), c(0.035532275913560465, 3.7351504825512545)),
+        ];
+
+        for (z, ei_value, li_value) in references {
+            println!("z = {z}: {} against {ei_value}, {} against {li_value}", ei(z), li(z));
+            assert!((ei(z) - ei_value).norm() <= 1e-14 * ei_value.norm());
+            assert!((li(z) - li_value).norm() <= 1e-14 * li_value.norm());
+        }
+    }
+
+    #[test]
+    fn branch_cuts_and_special_cases() {
+        // DLMF 6.2.6, either side of the cut of E1 along the negative real axis
+        for x in [0.5, 3.0, 20.0] {
+            let above = e1(c(-x, 0.0));
+            let below = e1(c(-x, -0.0));
+            assert_eq!(above, below.conj());
+            assert!((above + ei(x) + c(0.0, PI)).norm() <= 1e-14 * above.norm());
+        }
+
+        assert!(e1(-1.0f64).is_nan());
+        assert!(li(-1.0f64).is_nan());
+        assert_eq!(e1(0.0), f64::INFINITY);
+        assert_eq!(ei(0.0), f64::NEG_INFINITY);
+        assert_eq!(li(0.0), 0.0);
+        assert_eq!(li(1.0), f64::NEG_INFINITY);
+        assert_eq!(e_n(3, 0.0), 0.5);
+        assert!((ein(1e-300f64) - 1e-300).abs() <= 1e-314);
+    }
+
+    #[test]
+    fn recurrence_and_relations() {
+        for z in [c(0.3, 0.0), c(2.0, -1.0), c(-3.5, 0.2), c(15.0, 4.0)] {
+            // DLMF 8.19.12, p E_(p + 1)(z) + z E_p(z) = e^-z
+            for n in 1..5 {
+                let lhs = n as f64 * e_n(n + 1, z) + z * e_n(n, z);
+                assert!((lhs - (-z).exp()).norm() <= 1e-13 * (-z).exp().norm(), "n = {n}, z = {z}: {lhs} against {}", (-z).exp());
+            }
+
+            // DLMF 8.19.7, E_0(z) = e^-z / z
+            assert!((e_n(0, z) - (-z).exp() / z).norm() <= 1e-14 * e_n(0, z).norm());
+            // DLMF 6.2.4
+            assert!((ein(z) - e1(z) - z.ln() - crate::EM_GAMMA).norm() <= 1e-13 * ein(z).norm().max(1.0));
+        }
+    }
+}
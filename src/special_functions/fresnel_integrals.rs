@@ -0,0 +1,227 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_6, PI};
+use num::{complex::{Complex64, ComplexFloat}, Float, NumCast, Zero};
+use crate::polynomials::Rational;
+use super::{faddeeva, to_complex64, from_complex64};
+
+// Within this the Padé approximants are accurate over the whole disk
+const RATIONAL_RADIUS : f64 = 1.6;
+// Beyond this, and within a quarter turn of the positive real axis, the asymptotic expansions reach machine precision before they diverge
+const ASYMPTOTIC_RADIUS : f64 = 5.0;
+const ASYMPTOTIC_MAX_TERMS : i32 = 100;
+
+/// Fresnel integral C(z) (DLMF 7.2.7), an odd entire function tending to 1/2 along the positive real axis
+pub fn fresnel_c<TNum : ComplexFloat>(z : TNum) -> TNum {
+    if z.re().is_sign_negative() {
+        return -fresnel_c(-z);
+    }
+
+    if z.abs() <= NumCast::from(RATIONAL_RADIUS).unwrap() {
+        return z * C_RATIONAL.evaluate(z * z * z * z);
+    }
+
+    // DLMF 7.5.3
+    let (f, g) = auxiliary(z);
+    let theta = half_pi_square(z);
+    <TNum as NumCast>::from(0.5).unwrap() + f * theta.sin() - g * theta.cos()
+}
+
+/// Fresnel integral S(z) (DLMF 7.2.8), an odd entire function tending to 1/2 along the positive real axis
+pub fn fresnel_s<TNum : ComplexFloat>(z : TNum) -> TNum {
+    if z.re().is_sign_negative() {
+        return -fresnel_s(-z);
+    }
+
+    if z.abs() <= NumCast::from(RATIONAL_RADIUS).unwrap() {
+        return z * z * z * S_RATIONAL.evaluate(z * z * z * z);
+    }
+
+    // DLMF 7.5.4
+    let (f, g) = auxiliary(z);
+    let theta = half_pi_square(z);
+    <TNum as NumCast>::from(0.5).unwrap() - f * theta.cos() - g * theta.sin()
+}
+
+/// Auxiliary Fresnel function f(z) (DLMF 7.2.10), which decays like 1 / (π z) along the positive real axis
+pub fn fresnel_f<TNum : ComplexFloat>(z : TNum) -> TNum {
+    auxiliary(z).0
+}
+
+/// Auxiliary Fresnel function g(z) (DLMF 7.2.11), which decays like 1 / (π^2 z^3) along the positive real axis
+pub fn fresnel_g<TNum : ComplexFloat>(z : TNum) -> TNum {
+    auxiliary(z).1
+}
+
+// f(z) and g(z) together, which C(z) and S(z) are built from outside the disk of the Padé approximants
+fn auxiliary<TNum : ComplexFloat>(z : TNum) -> (TNum, TNum) {
+    if z.abs() >= NumCast::from(ASYMPTOTIC_RADIUS).unwrap() && Float::abs(z.im()) <= z.re() {
+        asymptotic_auxiliary(z)
+    }
+    else {
+        faddeeva_auxiliary(z)
+    }
+}
+
+// DLMF 7.12.2 and 7.12.3, summed until the terms fall below machine precision, which keeps g accurate relative to itself
+// rather than to f as the Faddeeva form below would be
+fn asymptotic_auxiliary<TNum : ComplexFloat>(z : TNum) -> (TNum, TNum) {
+    let t = <TNum as NumCast>::from(FRAC_PI_2).unwrap() * z * z;
+    let inverse_t_squared = TNum::one() / (t * t);
+    let prefactor = TNum::one() / (<TNum as NumCast>::from(PI).unwrap() * z);
+
+    let sum = |first_term : TNum, first_rising : f64| {
+        let mut term = first_term;
+        let mut total = TNum::zero();
+        for m in 0..ASYMPTOTIC_MAX_TERMS {
+            total = total + term;
+            let rising = first_rising + 2.0 * m as f64;
+            let next_term = -term * inverse_t_squared * NumCast::from(rising * (rising + 1.0)).unwrap();
+            if next_term.abs() >= term.abs() || next_term.abs() <= TNum::Real::epsilon() * total.abs() {
+                break;
+            }
+            term = next_term;
+        }
+        total
+    };
+
+    let f = prefactor * sum(TNum::one(), 0.5);
+    let g = prefactor * sum(<TNum as NumCast>::from(0.5).unwrap() / t, 1.5);
+    (f, g)
+}
+
+// From DLMF 7.5.10 as g(z) ± i f(z) = (1 ± i) / 2 w(√π (±1 + i) z / 2), which holds on the whole complex plane
+fn faddeeva_auxiliary<TNum : ComplexFloat>(z : TNum) -> (TNum, TNum) {
+    let z = to_complex64(z);
+    let scale = PI.sqrt() / 2.0;
+    let plus = Complex64::new(0.5, 0.5) * faddeeva(Complex64::new(scale, scale) * z);
+    let minus = Complex64::new(0.5, -0.5) * faddeeva(Complex64::new(-scale, scale) * z);
+
+    let f = (plus - minus) / Complex64::new(0.0, 2.0);
+    let g = (plus + minus) / 2.0;
+    (from_complex64(f), from_complex64(g))
+}
+
+// π z^2 / 2, reducing z^2 modulo 4 exactly for real arguments so that the sine and cosine keep their accuracy for large z
+fn half_pi_square<TNum : ComplexFloat>(z : TNum) -> TNum {
+    let half_pi = <TNum as NumCast>::from(FRAC_PI_2).unwrap();
+    if !z.im().is_zero() {
+        return half_pi * z * z;
+    }
+
+    let x = z.re();
+    let four = <TNum::Real as NumCast>::from(4).unwrap();
+    let square = x * x;
+    let square_error = x.mul_add(x, -square);
+    half_pi * <TNum as NumCast>::from(square % four + square_error % four).unwrap()
+}
+
+// [7/7] Padé approximants in z^4 of C(z) / z and S(z) / z^3, from the power series DLMF 7.6.4 and 7.6.6
+const C_RATIONAL : Rational<f64> = Rational::from_static(&C_NUMERATOR, &C_DENOMINATOR);
+const S_RATIONAL : Rational<f64> = Rational::from_static(&S_NUMERATOR, &S_DENOMINATOR);
+
+const C_NUMERATOR : [f64; 8] = [
+    1.0,
+    -0.21809727530466802,
+    0.0215285709812169,
+    -0.000894977136448711,
+    1.8747567815633388e-05,
+    -2.074938887355557e-07,
+    1.1737716317352704e-09,
+    -2.7139868120954865e-12,
+];
+
+const C_DENOMINATOR : [f64; 8] = [
+    1.0,
+    0.028642834722565957,
+    0.0004104062942604768,
+    3.857050177261917e-06,
+    2.601842273819942e-08,
+    1.2792727380009524e-10,
+    4.322885816959407e-13,
+    7.916084289989322e-16,
+];
+
+const S_NUMERATOR : [f64; 8] = [
+    FRAC_PI_6,
+    -0.07848750413830169,
+    0.004994534797155005,
+    -0.0001515709633953045,
+    2.459867197693324e-06,
+    -2.2005738651512214e-08,
+    1.0363999844967246e-10,
+    -2.04090135926351e-13,
+];
+
+const S_DENOMINATOR : [f64; 8] = [
+    1.0,
+    0.02634284467906289,
+    0.00034508048597185173,
+    2.9449709353130815e-06,
+    1.7900034459898397e-08,
+    7.859118938215255e-11,
+    2.3465502823806236e-13,
+    3.7491102467185386e-16,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{fresnel_c, fresnel_s, fresnel_f, fresnel_g};
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_real_reference_values() {
+        let references : [(f64, f64, f64, f64, f64); 5] = [
+            (0.5, 0.4923442258714464, 0.06473243285999927, 0.39920505852570226, 0.17364269961323775),
+            (1.5, 0.4452611760398215, 0.6975049600820931, 0.20341843122601397, 0.025009796942798094),
+            (2.5, 0.45741300964177706, 0.6191817558195929, 0.1264069204948636, 0.006263634649122138),
+            (7.3, 0.5392680156584625, 0.5189473278581442, 0.043599432595598814, 0.0002603153070819887),
+            (1000.0, 0.4999999998986788, 0.4996816901138163, 0.00031830988618369394, 1.0132118364218378e-10),
+        ];
+
+        for (x, c_value, s_value, f_value, g_value) in references {
+            println!("x = {x}: {} {} {} {}", fresnel_c(x), fresnel_s(x), fresnel_f(x), fresnel_g(x));
+            assert!((fresnel_c(x) - c_value).abs() <= 1e-14 * c_value.abs());
+            assert!((fresnel_s(x) - s_value).abs() <= 1e-14 * s_value.abs());
+            assert!((fresnel_f(x) - f_value).abs() <= 1e-14 * f_value.abs());
+            assert!((fresnel_g(x) - g_value).abs() <= 1e-14 * g_value.abs());
+            assert!((fresnel_c(-x) + c_value).abs() <= 1e-14 * c_value.abs());
+        }
+
+        assert!((fresnel_c(0.5f32) - 0.49234423f32).abs() <= 1e-6);
+    }
+
+    #[test]
+    fn matches_complex_reference_values() {
+        let references = [
+            (c(1.0, 1.0), c(2.555793778102439, 2.555793778102439), c(-2.0618882191948407, 2.0618882191948407), c(0.18109233980951234, -0.15948538067762622), c(-0.01845579258978489, -0.040062751721671015)),
+            (c(2.0, -1.0), c(-36.22568799288165, -16.08787137412548), c(-15.587751104404587, 36.725464883991435), c(0.128319385278646, 0.062244782143126606), c(0.002158649807415445, 0.008846436296842985)),
+            (c(-0.5, 3.0), c(-1.518755972400619, 6.175406386811256), c(5.674634479713996, 1.0192710374417915), c(72.70489672060863, -30.22535934718492), c(-30.120715927135397, -72.71870135849778)),
+            (c(6.0, 2.0), c(184828758483626.5, 566777992590804.8), c(-566777992590804.2, 184828758483626.0), c(0.047746825270805746, -0.0159059450630123), c(0.0002282109090478349, -0.0003289978048736407)),
+        ];
+
+        for (z, c_value, s_value, f_value, g_value) in references {
+            println!("z = {z}: {} {} {} {}", fresnel_c(z), fresnel_s(z), fresnel_f(z), fresnel_g(z));
+            assert!((fresnel_c(z) - c_value).norm() <= 1e-13 * c_value.norm());
+            assert!((fresnel_s(z) - s_value).norm() <= 1e-13 * s_value.norm());
+            assert!((fresnel_f(z) - f_value).norm() <= 1e-13 * f_value.norm());
+            assert!((fresnel_g(z) - g_value).norm() <= 1e-13 * g_value.norm());
+        }
+    }
+
+    #[test]
+    fn symmetries() {
+        let i = c(0.0, 1.0);
+        for z in [c(0.3, 0.2), c(1.7, -0.4), c(4.0, 1.0), c(-9.0, 0.5)] {
+            // DLMF 7.4.8
+            assert!((fresnel_c(i * z) - i * fresnel_c(z)).norm() <= 1e-13 * fresnel_c(z).norm());
+            assert!((fresnel_s(i * z) + i * fresnel_s(z)).norm() <= 1e-13 * fresnel_s(z).norm());
+            // DLMF 7.4.7
+            assert!((fresnel_s(-z) + fresnel_s(z)).norm() <= 1e-14 * fresnel_s(z).norm());
+            assert!((fresnel_c(z.conj()) - fresnel_c(z).conj()).norm() <= 1e-14 * fresnel_c(z).norm());
+        }
+
+        assert_eq!(fresnel_c(0.0), 0.0);
+        assert_eq!(fresnel_s(0.0), 0.0);
+        assert_eq!(fresnel_f(0.0), 0.5);
+        assert_eq!(fresnel_g(0.0), 0.5);
+    }
+}
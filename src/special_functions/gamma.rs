@@ -1,41 +1,366 @@
-use num::complex::Complex64;
+use num::complex::{Complex64, ComplexFloat};
+use num::{NumCast, ToPrimitive};
 use std::f64::consts::{PI,TAU};
 
+use crate::polynomials::Polynomial;
+use super::{to_complex64, from_complex64};
+
 const G : usize = 7;
 
 const G_PLUS_HALF : f64 = G as f64 + 0.5;
 
 const P: [f64 ;G+2] = [
-    0.99999999999980993,
+    0.9999999999998099,
     676.5203681218851,
     -1259.1392167224028,
-    771.32342877765313,
-    -176.61502916214059,
+    771.3234287776531,
+    -176.6150291621406,
     12.507343278686905,
     -0.13857109526572012,
-    9.9843695780195716e-6,
+    9.984369578019572e-6,
     1.5056327351493116e-7
 ];
 
-pub fn gamma(input : Complex64) -> Complex64{
+// B_2, B_4, ..., B_30 for the asymptotic expansions of the polygamma functions
+const EVEN_BERNOULLI_NUMBERS : [f64; 15] = [
+    0.16666666666666666,
+    -0.03333333333333333,
+    0.023809523809523808,
+    -0.03333333333333333,
+    0.07575757575757576,
+    -0.2531135531135531,
+    1.1666666666666667,
+    -7.092156862745098,
+    54.971177944862156,
+    -529.1242424242424,
+    6192.123188405797,
+    -86580.25311355312,
+    1425517.1666666667,
+    -27298231.067816094,
+    601580873.9006424,
+];
+
+// The asymptotic expansions are used once |z| exceeds this plus the order, so that B_30 is never needed
+const POLYGAMMA_ASYMPTOTIC_RADIUS : f64 = 20.0;
+
+/// The gamma function (DLMF 5.2.1) by Lanczos' approximation, continued to Re z < 1/2 by reflection (DLMF 5.5.3).
+/// The poles at the non-positive integers give infinity.
+pub fn gamma<TNum : ComplexFloat>(input : TNum) -> TNum {
+    let z = to_complex64(input);
+    let value = complex_gamma(z);
+    // The reflection leaves rounding in the imaginary part on the real axis, which real types could not hold
+    if z.im == 0.0 {
+        return from_complex64(Complex64::new(value.re, 0.0));
+    }
+    from_complex64(value)
+}
+
+fn complex_gamma(input : Complex64) -> Complex64 {
+    if is_pole(input) {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    if input.re < 0.5 {
+        // In polar form, since complex division squares the divisor, which overflows long before Γ(1 - z) does
+        let denominator = complex_gamma(1.0 - input) * sin_pi(input);
+        return Complex64::from_polar(PI / denominator.norm(), -denominator.arg());
+    }
+
+    let z = input - 1.0;
+    let t = z + G_PLUS_HALF;
+    // t^(z + 1/2) is split in two so that it does not overflow before e^-t brings it back down
+    let half_power = t.powc((z + 0.5) / 2.0);
+    TAU.sqrt() * half_power * ((-t).exp() * half_power) * lanczos_sum(z)
+}
+
+/// The principal branch of the logarithm of the gamma function, which is analytic away from the negative real axis and real on the positive one.
+/// Its imaginary part is continuous rather than reduced to (-π, π], so that log Γ(z + 1) = log Γ(z) + log z holds everywhere (DLMF 5.5.1).
+/// On the negative real axis it takes the limit from above, and at the poles its real part is infinite.
+/// Real types cannot hold the odd multiples of iπ where Γ(x) < 0, so for them this is log |Γ(x)| as in C's lgamma.
+pub fn lgamma<TNum : ComplexFloat>(input : TNum) -> TNum {
+    let value = complex_lgamma(to_complex64(input));
+    let result : TNum = from_complex64(value);
+    if result.is_nan() && !ComplexFloat::is_nan(value) {
+        return from_complex64(Complex64::new(value.re, 0.0));
+    }
+    result
+}
+
+fn complex_lgamma(input : Complex64) -> Complex64 {
+    if is_pole(input) {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    if input.im < 0.0 {
+        return complex_lgamma(input.conj()).conj();
+    }
+
     if input.re < 0.5 {
-        return PI / (gamma(1.0 - input) * (PI * input).sin());
+        // The logarithm of sin(πz) = e^(-iπz) (1 - e^(2πiz)) / 2i, whose last factor has a positive real part in the upper half plane,
+        // is continuous there and real on (0, 1), so the reflection formula keeps to the principal branch
+        let log_sin = -Complex64::i() * PI * input - 2.0f64.ln() + Complex64::i() * PI / 2.0
+            + (1.0 - (2.0 * Complex64::i() * PI * reduce_real_part(input)).exp()).ln();
+        return PI.ln() - log_sin - complex_lgamma(1.0 - input);
     }
 
     let z = input - 1.0;
     let t = z + G_PLUS_HALF;
-    let x = P[0] + P.iter().skip(1).enumerate().map(|(i, x)| x / (z + i as f64)).sum::<Complex64>();
-    return TAU.sqrt() * t.powc(z + 0.5) * (-t).exp() * x;
+    0.5 * TAU.ln() + (z + 0.5) * t.ln() - t + lanczos_sum(z).ln()
+}
+
+/// The digamma function ψ(z) = Γ'(z) / Γ(z) (DLMF 5.2.2), so that the derivative of the gamma function is Γ(z) ψ(z)
+pub fn digamma<TNum : ComplexFloat>(input : TNum) -> TNum {
+    polygamma(0, input)
+}
+
+/// The polygamma function ψ^(n)(z), the nth derivative of the digamma function (DLMF 5.15.1), so that trigamma is n = 1.
+/// Left of Re z = 1/2 and near enough to the real axis that the asymptotic expansion (DLMF 5.11.2, 5.15.8) would not converge this reflects (DLMF 5.15.6),
+/// and it then recurs upwards (DLMF 5.15.5) until the expansion converges, which takes a number of steps bounded independently of z.
+/// The poles at the non-positive integers give infinity.
+pub fn polygamma<TNum : ComplexFloat>(order : u32, input : TNum) -> TNum {
+    let cast = |x : f64| <TNum as NumCast>::from(x).unwrap();
+    let re = input.re().to_f64().unwrap();
+    let im = input.im().to_f64().unwrap();
+    if im == 0.0 && re <= 0.0 && re == re.round() {
+        return cast(f64::INFINITY);
+    }
+
+    let radius = POLYGAMMA_ASYMPTOTIC_RADIUS + order as f64;
+    let sign = if order.is_multiple_of(2) { 1.0 } else { -1.0 };
+    if re < 0.5 && im.abs() < radius {
+        // cot(πz) has period 1, so the real part is reduced first.
+        // Further from the real axis cot(πz) approaches ±i, where its derivatives, as polynomials in it, cancel catastrophically
+        let cot_derivative = if im.abs() < 0.5 {
+            let reduced = cast(PI) * (input - cast(re.round()));
+            let cot = reduced.cos() / reduced.sin();
+            cot_derivative_polynomial(order).evaluate(cot)
+        }
+        else {
+            from_complex64(cot_derivative_off_axis(order, PI * (to_complex64(input) - re.round())))
+        };
+        return cast(sign) * polygamma(order, TNum::one() - input) - cot_derivative * cast(PI.powi(order as i32 + 1));
+    }
+
+    // ψ^(n)(z) = ψ^(n)(z + N) - (-1)^n n! sum_k (z + k)^(-n-1), shifting far enough from the origin for the expansion to converge
+    let mut shifted = input;
+    let mut recurrence_sum = TNum::zero();
+    while shifted.abs().to_f64().unwrap() < radius {
+        recurrence_sum = recurrence_sum + shifted.powi(-(order as i32) - 1);
+        shifted = shifted + TNum::one();
+    }
+
+    let factorial = libm::tgamma(order as f64 + 1.0);
+    polygamma_asymptotic_expansion(order, shifted) - cast(sign * factorial) * recurrence_sum
+}
+
+// The nth derivative of cot(x) as a polynomial in cot(x), from d cot(x) / dx = -(1 + cot(x)^2)
+fn cot_derivative_polynomial(order : u32) -> Polynomial<f64> {
+    let one_plus_square = Polynomial::new(vec![1.0, 0.0, 1.0]);
+    let mut polynomial = Polynomial::new(vec![0.0, 1.0]);
+    for _ in 0..order {
+        polynomial = -(&one_plus_square * &polynomial.derivative());
+    }
+
+    polynomial
+}
+
+// The nth derivative of cot(x) for |Im x| >= π/2, from cot(x) = i + r with r = 2i / (u - 1) and u = e^(2ix) in the upper half plane,
+// where r = -2i sum_k u^k gives (-1)^n u A_n(u) r^(n+1) for n >= 1, with A_n the Eulerian polynomial.
+// There |u| <= e^-π, so that neither u - 1 nor A_n(u), whose coefficients are positive, cancels
+fn cot_derivative_off_axis(order : u32, x : Complex64) -> Complex64 {
+    if x.im < 0.0 {
+        return cot_derivative_off_axis(order, x.conj()).conj();
+    }
+
+    let u = (2.0 * Complex64::i() * x).exp();
+    let r = 2.0 * Complex64::i() / (u - 1.0);
+    if order == 0 {
+        return Complex64::i() + r;
+    }
+
+    let sign = if order.is_multiple_of(2) { 1.0 } else { -1.0 };
+    sign * u * eulerian_polynomial(order).evaluate(u) * r.powi(order as i32 + 1)
+}
+
+// A_n(u) from A_1(u) = 1 and A_n(u) = (1 + (n - 1) u) A_n-1(u) + u (1 - u) A_n-1'(u), so that sum_k k^n u^k = u A_n(u) / (1 - u)^(n+1)
+fn eulerian_polynomial(order : u32) -> Polynomial<f64> {
+    let u_minus_square = Polynomial::new(vec![0.0, 1.0, -1.0]);
+    let mut polynomial = Polynomial::new(vec![1.0]);
+    for n in 2..=order {
+        let factor = Polynomial::new(vec![1.0, (n - 1) as f64]);
+        polynomial = &(&factor * &polynomial) + &(&u_minus_square * &polynomial.derivative());
+    }
+
+    polynomial
+}
+
+// DLMF 5.11.2 for the digamma function, and DLMF 5.15.8 written as (-1)^(n+1) (n-1)! / z^n (1 + n / 2z + sum_k B_2k binomial(2k+n-1, 2k) / z^2k) otherwise
+fn polygamma_asymptotic_expansion<TNum : ComplexFloat>(order : u32, input : TNum) -> TNum {
+    let cast = |x : f64| <TNum as NumCast>::from(x).unwrap();
+    let inverse_square = (input * input).recip();
+    let n = order as f64;
+
+    let mut sum = if order == 0 { input.ln() - (cast(2.0) * input).recip() } else { TNum::one() + cast(n / 2.0) / input };
+    let mut power = TNum::one();
+    let mut binomial = 1.0;
+    for (k, bernoulli) in EVEN_BERNOULLI_NUMBERS.iter().enumerate() {
+        let two_k = 2.0 * (k + 1) as f64;
+        power = power * inverse_square;
+        let term = if order == 0 {
+            -power * cast(bernoulli / two_k)
+        }
+        else {
+            binomial *= (two_k + n - 2.0) * (two_k + n - 1.0) / ((two_k - 1.0) * two_k);
+            power * cast(bernoulli * binomial)
+        };
+
+        sum = sum + term;
+        if term.abs() <= sum.abs() * <TNum::Real as NumCast>::from(f64::EPSILON).unwrap() {
+            break;
+        }
+    }
+
+    if order == 0 {
+        return sum;
+    }
+
+    let sign = if order.is_multiple_of(2) { -1.0 } else { 1.0 };
+    cast(sign * libm::tgamma(n)) * input.powi(-(order as i32)) * sum
+}
+
+fn lanczos_sum(z : Complex64) -> Complex64 {
+    P[0] + P.iter().skip(1).enumerate().map(|(i, x)| x / (z + (i + 1) as f64)).sum::<Complex64>()
+}
+
+pub(super) fn is_pole(input : Complex64) -> bool {
+    input.im == 0.0 && input.re <= 0.0 && input.re == input.re.round()
+}
+
+// sin(πz), with the real part of z first reduced by a multiple of 2 so that the product with π loses nothing
+fn sin_pi(input : Complex64) -> Complex64 {
+    (PI * reduce_real_part(input)).sin()
+}
+
+fn reduce_real_part(input : Complex64) -> Complex64 {
+    input - 2.0 * (input.re / 2.0).round()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::special_functions::gamma;
+    use crate::special_functions::{gamma, lgamma, digamma, polygamma};
+    use crate::EM_GAMMA;
     use libm::tgamma;
     use num::complex::Complex64;
+    use std::f64::consts::PI;
 
     #[test]
     fn gamma_test_20() {
-        // assert_eq!(gamma(Complex64::from(20.0)), Complex64::from(tgamma(20.0)));
+        let value = gamma(Complex64::from(20.0));
+        assert!((value.re - tgamma(20.0)).abs() <= 1e-14 * tgamma(20.0) && value.im == 0.0);
+    }
+
+    #[test]
+    fn matches_libm_on_the_real_axis() {
+        for x in [0.001, 0.3, 0.5, 1.0, 2.5, 7.0, 33.3, 101.5, 170.5, -0.5, -2.3, -17.9, -150.5] {
+            let value = gamma(Complex64::from(x));
+            assert!((value.re - tgamma(x)).abs() <= 1e-12 * tgamma(x).abs(), "{value} against {} at {x}", tgamma(x));
+
+            let log = lgamma(Complex64::from(x));
+            assert!((log.re - libm::lgamma(x)).abs() <= 1e-13 * libm::lgamma(x).abs().max(1.0), "{log} against {} at {x}", libm::lgamma(x));
+        }
+
+        assert!(gamma(Complex64::from(-3.0)).re.is_infinite());
+        assert_eq!(lgamma(Complex64::from(1.0)), Complex64::from(0.0));
+        // The sign of Γ(-2.5) < 0 shows up as an odd multiple of π
+        assert!((lgamma(Complex64::from(-2.5)).im + 3.0 * PI).abs() < 1e-14);
+    }
+
+    #[test]
+    fn real_arguments_give_real_values() {
+        for x in [0.001, 0.5, 2.5, 33.3, 170.5, -0.5, -2.3, -17.9, -150.5] {
+            assert!((gamma(x) - tgamma(x)).abs() <= 1e-12 * tgamma(x).abs(), "{} against {} at {x}", gamma(x), tgamma(x));
+            assert!((lgamma(x) - libm::lgamma(x)).abs() <= 1e-13 * libm::lgamma(x).abs().max(1.0), "{} against {} at {x}", lgamma(x), libm::lgamma(x));
+        }
+
+        assert!((gamma(4.5f32) - 11.631728f32).abs() <= 1e-5);
+        assert!(gamma(-3.0f64).is_infinite());
+        assert!(lgamma(0.0f64).is_infinite());
+    }
+
+    #[test]
+    fn satisfies_reflection_formula() {
+        // DLMF 5.5.3
+        for (re, im) in [(0.3, 0.2), (-4.6, 1.5), (2.0, -7.0), (12.5, 3.0), (-0.5, -0.01)] {
+            let z = Complex64::new(re, im);
+            let product = gamma(z) * gamma(1.0 - z);
+            let expected = PI / (PI * z).sin();
+            assert!((product - expected).norm() <= 1e-13 * expected.norm(), "{product} against {expected} at {z}");
+        }
+    }
+
+    #[test]
+    fn log_gamma_is_on_the_principal_branch() {
+        // From mpmath
+        let references = [
+            ((3.0, 4.0), (-1.7566267846037842, 4.742664438034658)),
+            ((-2.5, 1e-3), (-0.05624848611288207, -9.4236748041107)),
+            ((0.5, 100.0), (-156.16069414628498, 360.5174352679064)),
+            ((-40.0, -60.0), (-261.87701988778707, -109.26009892117702)),
+        ];
+        for ((re, im), (expected_re, expected_im)) in references {
+            let z = Complex64::new(re, im);
+            let expected = Complex64::new(expected_re, expected_im);
+            let value = lgamma(z);
+            assert!((value - expected).norm() <= 1e-13 * expected.norm(), "{value} against {expected} at {z}");
+            assert!((lgamma(z + 1.0) - value - z.ln()).norm() <= 1e-12 * value.norm());
+            assert!((value.exp() - gamma(z)).norm() <= 1e-11 * gamma(z).norm());
+        }
+    }
+
+    #[test]
+    fn polygamma_special_values() {
+        // DLMF 5.4.12, 5.4.13 and 5.15.2
+        assert!((digamma(1.0) + EM_GAMMA).abs() < 1e-15);
+        assert!((digamma(0.5) + EM_GAMMA + 2.0 * 2.0f64.ln()).abs() < 1e-15);
+        assert!((polygamma(1, 1.0) - PI * PI / 6.0).abs() < 1e-15);
+        // -2 zeta(3), from mpmath
+        assert!((polygamma(2, 1.0f64) + 2.4041138063191885).abs() < 1e-14);
+        assert!(digamma(-2.0f64).is_infinite());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn polygamma_matches_reference_values() {
+        // From mpmath
+        let references = [
+            (0, (3.0, 4.0), (1.550359817333411, 1.0105022091860445)),
+            (0, (-2.5, 0.1), (1.1036973777788084, 0.9226992914585989)),
+            (1, (-7.3, 0.0), (14.951383181433922, 0.0)),
+            (2, (0.2, -15.0), (0.004444045969068296, 0.00017803145408161334)),
+            (3, (-40.0, 25.0), (1.6320384901475918e-6, -1.847673299449982e-5)),
+            (6, (1.5, 0.5), (18.08910589038059, 23.748643070681897)),
+            (0, (-1e9, 1.0), (20.723265837446412, 3.1533480939371623)),
+            (1, (-7.3, 0.8), (-0.04393760314564333, 0.23137834404380742)),
+            (2, (-30.5, 3.0), (-0.0010115511853063812, -0.0001959677439192357)),
+            (4, (-12.25, -19.0), (1.5631823612954906e-5, -1.5340313712921087e-5)),
+            (0, (0.2, -6.0), (1.7918535790843342, -1.620871023168135)),
+        ];
+        for (order, (re, im), (expected_re, expected_im)) in references {
+            let z = Complex64::new(re, im);
+            let expected = Complex64::new(expected_re, expected_im);
+            let value = polygamma(order, z);
+            assert!((value - expected).norm() <= 1e-13 * expected.norm(), "{value} against {expected} for order {order} at {z}");
+            if im == 0.0 {
+                assert!((polygamma(order, re) - expected_re).abs() <= 1e-13 * expected_re.abs());
+            }
+        }
+    }
+
+    #[test]
+    fn digamma_is_the_logarithmic_derivative_of_gamma() {
+        for z in [Complex64::new(2.5, 0.0), Complex64::new(-1.3, 2.0), Complex64::new(6.0, -3.0)] {
+            let step = 1e-5;
+            let derivative = (gamma(z + step) - gamma(z - step)) / (2.0 * step);
+            assert!((derivative - gamma(z) * digamma(z)).norm() <= 1e-8 * derivative.norm());
+        }
+    }
+}
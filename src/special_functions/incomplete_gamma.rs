@@ -0,0 +1,241 @@
+use num::complex::Complex64;
+
+use crate::{continued_fraction, iterative_calculation, EM_GAMMA};
+use super::{gamma, lgamma, digamma};
+use super::gamma::is_pole;
+
+// The continued fraction is only used beyond this, where it converges quickly
+const CONTINUED_FRACTION_RADIUS : f64 = 1.5;
+const CONTINUED_FRACTION_MAX_TERMS : i32 = 10000;
+
+/// The lower incomplete gamma function γ(a, z) (DLMF 8.2.1), on the principal branch of z^a.
+/// It has the poles of Γ(a) at the non-positive integers, which give infinity.
+pub fn lower_gamma(a : Complex64, z : Complex64) -> Complex64 {
+    if z == Complex64::new(0.0, 0.0) {
+        return z;
+    }
+
+    if is_pole(a) {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+
+    if use_continued_fraction(a, z) {
+        let (log_prefactor, fraction) = upper_continued_fraction(a, z);
+        return gamma(a) - log_prefactor.exp() * fraction;
+    }
+
+    let (log_prefactor, sum) = lower_series(a, z);
+    log_prefactor.exp() * sum
+}
+
+/// The upper incomplete gamma function Γ(a, z) (DLMF 8.2.2), on the principal branch of z^a, so that Γ(0, z) = E1(z)
+pub fn upper_gamma(a : Complex64, z : Complex64) -> Complex64 {
+    if z == Complex64::new(0.0, 0.0) {
+        return gamma(a);
+    }
+
+    if use_continued_fraction(a, z) {
+        let (log_prefactor, fraction) = upper_continued_fraction(a, z);
+        return log_prefactor.exp() * fraction;
+    }
+
+    if is_pole(a) {
+        return negative_integer_upper_gamma(-a.re as i32, z);
+    }
+
+    let (log_prefactor, sum) = lower_series(a, z);
+    gamma(a) - log_prefactor.exp() * sum
+}
+
+/// The regularized lower incomplete gamma function P(a, z) = γ(a, z) / Γ(a) (DLMF 8.2.4), which stays finite for large a.
+/// At the poles of Γ(a) it takes its limiting value of 1.
+pub fn gamma_p(a : Complex64, z : Complex64) -> Complex64 {
+    if is_pole(a) {
+        return Complex64::new(1.0, 0.0);
+    }
+
+    if z == Complex64::new(0.0, 0.0) {
+        return z;
+    }
+
+    if use_continued_fraction(a, z) {
+        let (log_prefactor, fraction) = upper_continued_fraction(a, z);
+        return 1.0 - (log_prefactor - lgamma(a)).exp() * fraction;
+    }
+
+    let (log_prefactor, sum) = lower_series(a, z);
+    (log_prefactor - lgamma(a)).exp() * sum
+}
+
+/// The regularized upper incomplete gamma function Q(a, z) = Γ(a, z) / Γ(a) = 1 - P(a, z) (DLMF 8.2.4)
+pub fn gamma_q(a : Complex64, z : Complex64) -> Complex64 {
+    if is_pole(a) {
+        return Complex64::new(0.0, 0.0);
+    }
+
+    if z == Complex64::new(0.0, 0.0) {
+        return Complex64::new(1.0, 0.0);
+    }
+
+    if use_continued_fraction(a, z) {
+        let (log_prefactor, fraction) = upper_continued_fraction(a, z);
+        return (log_prefactor - lgamma(a)).exp() * fraction;
+    }
+
+    let (log_prefactor, sum) = lower_series(a, z);
+    1.0 - (log_prefactor - lgamma(a)).exp() * sum
+}
+
+// Past the transition near z = a the continued fraction gives Γ(a, z) without the cancellation that taking γ(a, z) from Γ(a) would suffer.
+// Near the negative real axis it converges slowly, but there the series has no cancellation.
+fn use_continued_fraction(a : Complex64, z : Complex64) -> bool {
+    z.norm() > CONTINUED_FRACTION_RADIUS.max(a.re + 1.0) && !near_negative_axis(z)
+}
+
+// Within a parabola around the negative real axis, where |e^-z| is close to e^|z|
+fn near_negative_axis(z : Complex64) -> bool {
+    z.norm() + z.re < 2.0
+}
+
+// Γ(a, z) as a logarithmic prefactor and the continued fraction multiplying its exponential,
+// from DLMF 8.9.2 in its even form, Γ(a, z) = z^a e^-z / (z + 1 - a - 1 (1 - a) / (z + 3 - a - 2 (2 - a) / (z + 5 - a - ...)))
+fn upper_continued_fraction(a : Complex64, z : Complex64) -> (Complex64, Complex64) {
+    let fraction = continued_fraction(f64::EPSILON, CONTINUED_FRACTION_MAX_TERMS, |n| {
+        let n = n as f64;
+        (-n * (n - a), z + 2.0 * n + 1.0 - a)
+    });
+    (a * z.ln() - z, 1.0 / fraction)
+}
+
+// γ(a, z) as a logarithmic prefactor and the series multiplying its exponential, in whichever form of DLMF 8.7.1 has no cancellation.
+// Away from the negative real axis the series is only used for |z| < Re a + 1, where the terms of the first form shrink from the start.
+fn lower_series(a : Complex64, z : Complex64) -> (Complex64, Complex64) {
+    if !near_negative_axis(z) {
+        // γ(a, z) = z^a e^-z sum_k z^k / (a)_(k+1)
+        let mut term = 1.0 / a;
+        let sum = iterative_calculation(f64::EPSILON, 0, 1, |k| {
+            if k > 0 {
+                term *= z / (a + k as f64);
+            }
+            term
+        });
+        (a * z.ln() - z, sum)
+    }
+    else {
+        // γ(a, z) = z^a sum_k (-z)^k / (k! (a + k)), whose terms are positive for negative z
+        let mut power = Complex64::new(1.0, 0.0);
+        let sum = iterative_calculation(f64::EPSILON, 0, 1, |k| {
+            if k > 0 {
+                power *= -z / k as f64;
+            }
+            power / (a + k as f64)
+        });
+        (a * z.ln(), sum)
+    }
+}
+
+// DLMF 8.4.15, Γ(-n, z) = (-1)^n / n! (ψ(n + 1) - ln z) - z^-n sum_(k != n) (-z)^k / (k! (k - n)), where Γ(a) - γ(a, z) would be infinity minus infinity
+fn negative_integer_upper_gamma(n : i32, z : Complex64) -> Complex64 {
+    // E1(z) = -γ - ln z + z - ..., where the series terms would be too small for the convergence test
+    if n == 0 && z.norm() < f64::EPSILON {
+        return -EM_GAMMA - z.ln();
+    }
+
+    let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+    let factorial = libm::tgamma(n as f64 + 1.0);
+
+    // z^-n is kept out of the sum, whose convergence test would overflow for large terms
+    let mut power = Complex64::new(1.0, 0.0);
+    let sum = iterative_calculation(f64::EPSILON, 0, 1, |k| {
+        if k > 0 {
+            power *= -z / k as f64;
+        }
+        if k == n { Complex64::new(0.0, 0.0) } else { power / (k - n) as f64 }
+    });
+
+    sign / factorial * (digamma(n as f64 + 1.0) - z.ln()) - z.powi(-n) * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lower_gamma, upper_gamma, gamma_p, gamma_q};
+    use crate::special_functions::{gamma, ein};
+    use crate::special_functions::test_support::c;
+    use crate::EM_GAMMA;
+
+    #[test]
+    fn matches_reference_values() {
+        let references = [
+            (c(3.7, 0.0), c(5.843480767139381, 0.0), c(3.621671802919316, 0.0), c(0.5489799808772882, 0.0), c(0.8683706985536098, 0.0)),
+            (c(20.0, 0.0), c(7.074797355540121, 0.0), c(6230559223917.209, 0.0), c(1.2163886984960808e17, 0.0), c(5.1219154762314135e-5, 0.0)),
+            (c(0.5, 0.0), c(-1.2180369149119625, -0.31839887915586423), c(0.9640964528914872, -3.4626182150436935), c(0.8083573980140288, 3.4626182150436935), c(0.5439331762567172, -1.9535731287303768)),
+            (c(0.3, -10.0), c(0.47812371864904185, 1.0072055683527417), c(4550.233908814817, -741.8589164750337), c(-4550.233908579042, 741.8589164400051), c(19339642010.587616, -273228301.0323616)),
+            (c(0.3, -10.0), c(-24.92950291320889, 0.0), c(2.4152865111797118e23, 1.653068487181745e23), c(-2.4152865111797118e23, -1.653068487181745e23), c(9.003661080544282e29, 8.348861870826003e29)),
+            (c(-1.5, 0.5), c(1.1754113723054345, 0.464967939672327), c(0.9041860674505097, 0.3958890772083236), c(0.0337305953373754, -0.04668340906051875), c(0.9846906126169391, 0.0554735148386215)),
+            (c(100.0, 0.0), c(-12.026631577607771, -38.56583307424988), c(-9.310133583845419e162, 6.038047169153061e163), c(9.310134517107574e162, -6.038047169153061e163), c(-9975903.918912793, 64698296.61934357)),
+        ];
+
+        for (a, z, lower, upper, p) in references {
+            println!("a = {a}, z = {z}: {} against {lower}, {} against {upper}, {} against {p}", lower_gamma(a, z), upper_gamma(a, z), gamma_p(a, z));
+            assert!((lower_gamma(a, z) - lower).norm() <= 1e-12 * lower.norm());
+            assert!((upper_gamma(a, z) - upper).norm() <= 1e-12 * upper.norm());
+            assert!((gamma_p(a, z) - p).norm() <= 1e-12 * p.norm());
+            assert!((gamma_q(a, z) - (1.0 - p)).norm() <= 1e-12 * (1.0 - p).norm());
+        }
+    }
+
+    #[test]
+    fn special_cases() {
+        for z in [c(0.2, 0.0), c(3.0, -4.0), c(-6.5, 0.1), c(30.0, 2.0)] {
+            // DLMF 8.4.5
+            assert!((upper_gamma(c(1.0, 0.0), z) - (-z).exp()).norm() <= 1e-14 * (-z).exp().norm());
+            // DLMF 8.4.15 for n = 1, as Γ(-1, z) = e^-z / z - E1(z) from DLMF 8.8.2
+            let expected = (-z).exp() / z - upper_gamma(c(0.0, 0.0), z);
+            assert!((upper_gamma(c(-1.0, 0.0), z) - expected).norm() <= 1e-12 * expected.norm());
+        }
+
+        // DLMF 8.4.4, with E1 from ein (DLMF 6.2.4) within the disk where that is summed from its own power series
+        for z in [c(0.2, 0.0), c(0.1, 0.1), c(-0.3, 0.1), c(1.5, -0.8), c(-1.9, 0.3), c(0.0, 1.2)] {
+            let e1 = ein(z) - z.ln() - EM_GAMMA;
+            assert!((upper_gamma(c(0.0, 0.0), z) - e1).norm() <= 1e-13 * e1.norm());
+        }
+
+        let a = c(2.5, 1.0);
+        assert_eq!(lower_gamma(a, c(0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(upper_gamma(a, c(0.0, 0.0)), gamma(a));
+        assert_eq!(gamma_p(c(-2.0, 0.0), c(1.0, 1.0)), c(1.0, 0.0));
+        assert_eq!(gamma_q(c(-2.0, 0.0), c(1.0, 1.0)), c(0.0, 0.0));
+        assert!(lower_gamma(c(-2.0, 0.0), c(1.0, 1.0)).re.is_infinite());
+    }
+
+    #[test]
+    fn negative_integer_orders_near_the_origin() {
+        // From mpmath, as n, z and Γ(-n, z), down to arguments whose powers would overflow the convergence test
+        let references = [
+            (20, c(0.05, 0.0), c(4.974093911808929e24, 0.0)),
+            (12, c(-0.1, 0.05), c(17423183944.01871, -17030787299.082123)),
+            (4, c(1e-40, 0.0), c(2.5e159, 0.0)),
+            (40, c(1e-4, 1e-4), c(2.383941259140478e152, -2.4450678016823115e148)),
+            (0, c(1e-200, 0.0), c(459.9398029339076, 0.0)),
+            (0, c(1e-170, -2e-170), c(390.0575311878692, 1.1071487177940905)),
+        ];
+
+        for (n, z, expected) in references {
+            println!("n = {n}, z = {z}: {} against {expected}", upper_gamma(c(-n as f64, 0.0), z));
+            assert!((upper_gamma(c(-n as f64, 0.0), z) - expected).norm() <= 1e-13 * expected.norm());
+        }
+    }
+
+    #[test]
+    fn lower_and_upper_sum_to_gamma() {
+        // DLMF 8.2.3, across the transition between the series and the continued fraction
+        for a in [c(0.7, 0.0), c(4.0, 2.0), c(-3.5, 0.0), c(12.0, -5.0)] {
+            for z in [c(1.4, 0.0), c(1.6, 0.1), c(a.re + 0.9, 0.0), c(a.re + 1.1, 0.0), c(-2.0, 3.0), c(0.5, -8.0)] {
+                let sum = lower_gamma(a, z) + upper_gamma(a, z);
+                let scale = gamma(a).norm().max(lower_gamma(a, z).norm());
+                assert!((sum - gamma(a)).norm() <= 1e-12 * scale, "a = {a}, z = {z}: {sum} against {}", gamma(a));
+                assert!((gamma_p(a, z) + gamma_q(a, z) - 1.0).norm() <= 1e-12 * gamma_p(a, z).norm().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::f64::consts::PI;
+use num::complex::Complex64;
+
+use super::{elliptic_k, theta_1, theta_2, theta_3, theta_4};
+use super::complete_elliptic_k::k_of_complementary_parameter;
+
+const LANDEN_MAX_ITERATIONS : usize = 100;
+
+/// Jacobi elliptic function sn(u|m) (DLMF 22.2.4) of the parameter m = k^2
+pub fn sn(u : Complex64, m : Complex64) -> Complex64 {
+    jacobi(u, m).0
+}
+
+/// Jacobi elliptic function cn(u|m) (DLMF 22.2.5) of the parameter m = k^2
+pub fn cn(u : Complex64, m : Complex64) -> Complex64 {
+    jacobi(u, m).1
+}
+
+/// Jacobi elliptic function dn(u|m) (DLMF 22.2.6) of the parameter m = k^2
+pub fn dn(u : Complex64, m : Complex64) -> Complex64 {
+    jacobi(u, m).2
+}
+
+/// Jacobi amplitude am(u|m) (DLMF 22.16.1), the inverse of F(φ, m) for real u and 0 <= m <= 1.
+/// Elsewhere it is the angle whose sine and cosine are sn and cn, taken on the branch that keeps am(u + 2K) = am(u) + π.
+pub fn am(u : Complex64, m : Complex64) -> Complex64 {
+    if u.im == 0.0 && m.im == 0.0 && (0.0..=1.0).contains(&m.re) {
+        return Complex64::new(landen(u.re, m.re).3, 0.0);
+    }
+
+    let (sn, cn, _) = jacobi(u, m);
+    let i = Complex64::new(0.0, 1.0);
+    let angle = -i * (cn + i * sn).ln();
+    // Where K is finite the amplitude grows like π u / (2K), and for real m >= 1 it stays within a quarter turn of 0
+    let expected = if m.im == 0.0 && m.re >= 1.0 { 0.0 } else { (PI * u / (2.0 * elliptic_k(m))).re };
+    angle + 2.0 * PI * ((expected - angle.re) / (2.0 * PI)).round()
+}
+
+fn jacobi(u : Complex64, m : Complex64) -> (Complex64, Complex64, Complex64) {
+    if m.im == 0.0 {
+        real_parameter_jacobi(u, m.re)
+    }
+    else {
+        theta_jacobi(u, m)
+    }
+}
+
+// For real m, moved into [0, 1] by DLMF 22.17, and then from real arguments to complex ones by the addition theorem (DLMF 22.8.1 to 22.8.3)
+// with Jacobi's imaginary transformation (DLMF 22.6(iv)) for the imaginary part
+fn real_parameter_jacobi(u : Complex64, m : f64) -> (Complex64, Complex64, Complex64) {
+    if m > 1.0 {
+        // The reciprocal parameter
+        let root = m.sqrt();
+        let (sn, cn, dn) = real_parameter_jacobi(root * u, 1.0 / m);
+        return (sn / root, dn, cn);
+    }
+
+    if m < 0.0 {
+        // The parameter -m / (1 - m) in [0, 1)
+        let root = (1.0 - m).sqrt();
+        let (sn, cn, dn) = real_parameter_jacobi(root * u, -m / (1.0 - m));
+        return (sn / (root * dn), cn / dn, 1.0 / dn);
+    }
+
+    let (s, c, d, _) = landen(u.re, m);
+    let (s1, c1, d1, _) = landen(u.im, 1.0 - m);
+    let denominator = c1 * c1 + m * s * s * s1 * s1;
+    (
+        Complex64::new(s * d1, c * d * s1 * c1) / denominator,
+        Complex64::new(c * c1, -s * d * s1 * d1) / denominator,
+        Complex64::new(d * c1 * d1, -m * s * c * s1) / denominator,
+    )
+}
+
+// sn, cn, dn and am of real x for 0 <= m <= 1, by the descending Landen transformation of the arithmetic-geometric mean (DLMF 22.20(ii))
+fn landen(x : f64, m : f64) -> (f64, f64, f64, f64) {
+    if m == 1.0 {
+        // DLMF 22.5(ii), where the arithmetic-geometric mean of 1 and 0 never converges
+        let sech = 1.0 / x.cosh();
+        return (x.tanh(), sech, sech, x.sinh().atan());
+    }
+
+    let mut a = vec![1.0];
+    let mut c = vec![m.sqrt()];
+    let mut b = (1.0 - m).sqrt();
+    while c[c.len() - 1] > f64::EPSILON * a[a.len() - 1] && a.len() < LANDEN_MAX_ITERATIONS {
+        let previous = a[a.len() - 1];
+        a.push((previous + b) / 2.0);
+        c.push((previous - b) / 2.0);
+        b = (previous * b).sqrt();
+    }
+
+    let last = a.len() - 1;
+    let mut phi = 2f64.powi(last as i32) * a[last] * x;
+    for n in (1..=last).rev() {
+        phi = (phi + (c[n] / a[n] * phi.sin()).asin()) / 2.0;
+    }
+
+    let (sine, cosine) = phi.sin_cos();
+    // dn^2 = cn^2 + (1 - m) sn^2, with no cancellation as m approaches 1
+    (sine, cosine, (cosine * cosine + (1.0 - m) * sine * sine).sqrt(), phi)
+}
+
+// DLMF 22.2.4 to 22.2.6 for complex m, with u first reduced modulo the periods 4K and 4iK' that sn, cn and dn share
+fn theta_jacobi(u : Complex64, m : Complex64) -> (Complex64, Complex64, Complex64) {
+    let k = elliptic_k(m);
+    let k_prime = k_of_complementary_parameter(m);
+    let nome = (-PI * k_prime / k).exp();
+
+    let first_period = 4.0 * k;
+    let second_period = Complex64::new(0.0, 4.0) * k_prime;
+    let determinant = (first_period.conj() * second_period).im;
+    let first_periods = ((u.conj() * second_period).im / determinant).round();
+    let second_periods = ((first_period.conj() * u).im / determinant).round();
+    let u = u - first_periods * first_period - second_periods * second_period;
+
+    let zeta = PI * u / (2.0 * k);
+    let zero = Complex64::new(0.0, 0.0);
+    let (theta_2_zero, theta_3_zero, theta_4_zero) = (theta_2(zero, nome), theta_3(zero, nome), theta_4(zero, nome));
+    let theta_4_zeta = theta_4(zeta, nome);
+    (
+        theta_3_zero / theta_2_zero * theta_1(zeta, nome) / theta_4_zeta,
+        theta_4_zero / theta_2_zero * theta_2(zeta, nome) / theta_4_zeta,
+        theta_4_zero / theta_3_zero * theta_3(zeta, nome) / theta_4_zeta,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sn, cn, dn, am};
+    use crate::special_functions::elliptic_f;
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_reference_values() {
+        let references = [
+            (c(0.7, 0.0), c(0.3, 0.0), c(0.6323047763108646, 0.0), c(0.7747197363269298, 0.0), c(0.9381136396814302, 0.0)),
+            (c(5.0, 0.0), c(0.99, 0.0), c(0.985572808289661, 0.0), c(-0.16925200016552566, 0.0), c(0.19585652188382902, 0.0)),
+            (c(1.0, 2.0), c(0.5, 0.0), c(1.7317588416708858, -0.15275187351700664), c(-0.1865632462996903, -1.4179074002600491), c(-0.18317508836572421, -0.7220670941255785)),
+            (c(20.0, -7.0), c(0.5, 0.0), c(-1.007757016554696, -0.08198770353097323), c(-0.2798500320188818, 0.2952427159235408), c(0.7063951492201738, -0.05848262378058307)),
+            (c(0.5, 0.0), c(-2.0, 0.0), c(0.5144937761473798, 0.0), c(0.8574941132775256, 0.0), c(1.236692237943127, 0.0)),
+            (c(1.0, 0.5), c(3.0, 0.0), c(0.7140042064631833, 0.000548845577442979), c(0.7001418482438265, -0.0005597123668238857), c(0.00161576075733275, -0.7276041008197944)),
+            (c(2.0, 1.0), c(1.0, 0.0), c(1.0147936161466335, 0.03381282607989669), c(0.1511762982655772, -0.2269736753937216), c(0.1511762982655772, -0.2269736753937216)),
+            (c(0.7, 0.0), c(0.3, 0.1), c(0.6323069952551166, -0.003978178129095844), c(0.7747349427606169, 0.0032468263925659146), c(0.9380716801602667, -0.020505028524324093)),
+            (c(10.0, -3.0), c(0.2, 0.8), c(-0.08513267064038592, 1.2828927931606104), c(1.6257550010351107, 0.067178689013738), c(1.2107358212985986, 0.5593868991603981)),
+            (c(5.0, 5.0), c(-3.0, 0.5), c(-1.0940070057133942, 0.027193493923077763), c(0.0664355123683209, 0.4478007589786814), c(-2.14276006441759, 0.18120441277689706)),
+        ];
+
+        for (u, m, sn_value, cn_value, dn_value) in references {
+            println!("u = {u}, m = {m}: {} {} {}", sn(u, m), cn(u, m), dn(u, m));
+            // The relative accuracy falls off in proportion to |u|
+            assert!((sn(u, m) - sn_value).norm() <= 1e-13 * sn_value.norm());
+            assert!((cn(u, m) - cn_value).norm() <= 1e-13 * cn_value.norm());
+            assert!((dn(u, m) - dn_value).norm() <= 1e-13 * dn_value.norm());
+        }
+    }
+
+    #[test]
+    fn inverts_elliptic_f() {
+        for (phi, m) in [(c(0.6, 0.0), c(0.5, 0.0)), (c(2.5, 0.0), c(0.9, 0.0)), (c(-7.0, 0.0), c(0.2, 0.0)), (c(1.0, 0.0), c(0.999999, 0.0))] {
+            let u = elliptic_f(phi, m);
+            println!("φ = {phi}, m = {m}: {}", am(u, m));
+            assert!((am(u, m) - phi).norm() <= 1e-14 * phi.norm());
+        }
+
+        for (phi, m) in [(c(0.8, 0.3), c(0.4, 0.0)), (c(0.5, -0.2), c(0.3, 0.5)), (c(1.1, 0.4), c(-2.0, 0.0)), (c(0.3, 0.1), c(4.0, 0.0))] {
+            let u = elliptic_f(phi, m);
+            println!("φ = {phi}, m = {m}: {} {}", sn(u, m), am(u, m));
+            assert!((sn(u, m) - phi.sin()).norm() <= 1e-14 * phi.sin().norm());
+            assert!((cn(u, m) - phi.cos()).norm() <= 1e-14 * phi.cos().norm());
+            assert!((am(u, m) - phi).norm() <= 1e-14 * phi.norm());
+        }
+    }
+
+    #[test]
+    fn identities() {
+        for (u, m) in [(c(0.3, 0.0), c(0.7, 0.0)), (c(1.5, -0.8), c(0.25, 0.0)), (c(-2.0, 1.0), c(0.6, -0.3)), (c(0.9, 0.4), c(-5.0, 0.0))] {
+            let (s, cc, d) = (sn(u, m), cn(u, m), dn(u, m));
+            // DLMF 22.6.1
+            assert!((s * s + cc * cc - 1.0).norm() <= 1e-14);
+            assert!((d * d + m * s * s - 1.0).norm() <= 1e-14 * (m * s * s).norm().max(1.0));
+            // sn is odd and cn and dn are even
+            assert!((sn(-u, m) + s).norm() <= 1e-15 * s.norm());
+            assert!((cn(-u, m) - cc).norm() <= 1e-15 * cc.norm());
+        }
+
+        // DLMF 22.5(ii), the limits m = 0 and m = 1
+        let u = c(0.8, 0.3);
+        assert!((sn(u, c(0.0, 0.0)) - u.sin()).norm() <= 1e-15);
+        assert!((dn(u, c(1.0, 0.0)) - 1.0 / u.cosh()).norm() <= 1e-15);
+        assert_eq!(am(c(0.0, 0.0), c(0.5, 0.0)), c(0.0, 0.0));
+    }
+}
@@ -1,10 +1,68 @@
+use num::{complex::{Complex64, ComplexFloat}, NumCast, ToPrimitive};
+
 mod bessel;
+mod bessel_real_order;
+mod bessel_zeros;
+mod spherical_bessel;
+mod airy;
 mod exponential_integrals;
+mod gamma;
+mod error_functions;
+mod fresnel_integrals;
+mod beta;
+mod incomplete_gamma;
 mod complete_elliptic_k;
+mod carlson;
+mod elliptic_integrals;
 mod trigonometric_integrals;
+mod theta_functions;
+mod jacobi_elliptic_functions;
+
+pub use self::bessel::{jn, yn, h1n, h2n, i_n, i_n_scaled, k_n, k_n_scaled};
+pub use self::bessel_zeros::{jn_zeros, jn_prime_zeros, yn_zeros, yn_prime_zeros};
+pub use self::bessel_real_order::{jv, yv, h1v, h2v, iv, iv_scaled, kv, kv_scaled};
+pub use self::spherical_bessel::{spherical_jn, spherical_yn, spherical_h1n, spherical_h2n, riccati_psi, riccati_xi};
+pub use self::airy::{airy_ai, airy_ai_prime, airy_bi, airy_bi_prime};
+pub use self::exponential_integrals::{ein, e1, e_n, e_p, ei, li};
+pub use self::gamma::{gamma, lgamma, digamma, polygamma};
+pub use self::incomplete_gamma::{lower_gamma, upper_gamma, gamma_p, gamma_q};
+pub use self::beta::{beta, incomplete_beta, regularized_beta, inverse_regularized_beta};
+pub use self::error_functions::{erf, erfc, erfcx, erfi, dawson, voigt, faddeeva};
+pub use self::fresnel_integrals::{fresnel_c, fresnel_s, fresnel_f, fresnel_g};
+pub use self::complete_elliptic_k::{k, k_prime, elliptic_k};
+pub use self::carlson::{carlson_rf, carlson_rc, carlson_rj, carlson_rd};
+pub use self::elliptic_integrals::{elliptic_e, elliptic_pi, elliptic_f, elliptic_e_incomplete, elliptic_pi_incomplete};
+pub use self::elliptic_integrals::{elliptic_e_modulus, elliptic_pi_modulus, elliptic_f_modulus, elliptic_e_incomplete_modulus, elliptic_pi_incomplete_modulus};
+pub use self::trigonometric_integrals::{capital_si, cin, ci, shi, chi, f, g};
+pub use self::theta_functions::{theta_1, theta_2, theta_3, theta_4, q};
+pub use self::jacobi_elliptic_functions::{sn, cn, dn, am};
+
+// Functions generic over ComplexFloat whose numerics are done in Complex64 convert through these
+fn to_complex64<TNum : ComplexFloat>(z : TNum) -> Complex64 {
+    Complex64::new(z.re().to_f64().unwrap(), z.im().to_f64().unwrap())
+}
+
+// The square root of -1 is the imaginary unit for complex types and NaN for real ones, which can only hold real values
+fn from_complex64<TNum : ComplexFloat>(value : Complex64) -> TNum {
+    let unit = <TNum as NumCast>::from(-1.0).unwrap().sqrt();
+    let real = <TNum as NumCast>::from(value.re).unwrap();
+    if !unit.is_nan() {
+        real + unit * <TNum as NumCast>::from(value.im).unwrap()
+    }
+    else if value.im == 0.0 {
+        real
+    }
+    else {
+        <TNum as NumCast>::from(f64::NAN).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test_support {
+    use num::complex::Complex64;
 
-pub use self::bessel::jn;
-pub use self::exponential_integrals::ein;
-pub use libm::tgamma as gamma;
-pub use self::complete_elliptic_k::k;
-pub use self::trigonometric_integrals::{capital_si, cin, ci, f, g};
\ No newline at end of file
+    // Shorthand for the complex arguments and reference values in the tests
+    pub(super) fn c(re : f64, im : f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+}
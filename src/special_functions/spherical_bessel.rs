@@ -0,0 +1,155 @@
+use num::complex::{Complex64, ComplexFloat};
+use num::{NumCast, ToPrimitive};
+
+// The downward recurrence is renormalised whenever it grows past this, well below where complex division would overflow
+const RESCALE_THRESHOLD : f64 = 1e100;
+
+/// The spherical Bessel functions of the first kind j_0(z), ..., j_N(z) (DLMF 10.47.3).
+/// They come from downward recurrence, started far enough above both N and |z| that j is dominant, then normalised against whichever of j_0 and j_1 is larger.
+pub fn spherical_jn<TNum : ComplexFloat>(argument : TNum, max_order : usize) -> Vec<TNum> {
+    let mut values = vec![TNum::zero(); max_order + 1];
+    if argument == TNum::zero() {
+        values[0] = TNum::one();
+        return values;
+    }
+
+    let size = (max_order as f64).max(argument.abs().to_f64().unwrap());
+    let start = (size + 10.0 * size.sqrt() + 30.0) as usize;
+    let rescale = <TNum as NumCast>::from(1.0 / RESCALE_THRESHOLD).unwrap();
+    let mut next = TNum::zero();
+    let mut curr = TNum::one();
+    for n in (1..=start).rev() {
+        if n <= max_order {
+            values[n] = curr;
+        }
+
+        // DLMF 10.51.1
+        let prev = <TNum as NumCast>::from(2 * n + 1).unwrap() / argument * curr - next;
+        next = curr;
+        curr = prev;
+        if curr.abs().to_f64().unwrap() > RESCALE_THRESHOLD {
+            curr = curr * rescale;
+            next = next * rescale;
+            for value in values.iter_mut().skip(n) {
+                *value = *value * rescale;
+            }
+        }
+    }
+
+    // DLMF 10.49.3
+    let j_0 = argument.sin() / argument;
+    let j_1 = (j_0 - argument.cos()) / argument;
+    let normalisation = if j_0.abs() >= j_1.abs() { j_0 / curr } else { j_1 / next };
+    values[0] = curr;
+    values.iter().map(|value| *value * normalisation).collect()
+}
+
+/// The spherical Bessel functions of the second kind y_0(z), ..., y_N(z) (DLMF 10.47.4), by upward recurrence, in which y is dominant
+pub fn spherical_yn<TNum : ComplexFloat>(argument : TNum, max_order : usize) -> Vec<TNum> {
+    // DLMF 10.49.4
+    let y_0 = -argument.cos() / argument;
+    let y_1 = (y_0 - argument.sin()) / argument;
+    upward_recurrence(argument, y_0, y_1, max_order)
+}
+
+/// The spherical Hankel functions of the first kind h^(1)_0(z), ..., h^(1)_N(z) (DLMF 10.47.5), by upward recurrence.
+/// Starting from their closed forms rather than adding j and y keeps them accurate in the upper half plane, where they are exponentially small.
+pub fn spherical_h1n(argument : Complex64, max_order : usize) -> Vec<Complex64> {
+    // DLMF 10.49.6
+    let exponential = (Complex64::i() * argument).exp();
+    let h_0 = -Complex64::i() * exponential / argument;
+    let h_1 = -exponential * (argument + Complex64::i()) / (argument * argument);
+    upward_recurrence(argument, h_0, h_1, max_order)
+}
+
+/// The spherical Hankel functions of the second kind h^(2)_0(z), ..., h^(2)_N(z) (DLMF 10.47.6)
+pub fn spherical_h2n(argument : Complex64, max_order : usize) -> Vec<Complex64> {
+    // DLMF 10.47.11, since j and y are real on the real axis
+    spherical_h1n(argument.conj(), max_order).iter().map(|h| h.conj()).collect()
+}
+
+/// The Riccati–Bessel functions ψ_n(z) = z j_n(z) for n = 0, ..., N, as used in Mie scattering
+pub fn riccati_psi<TNum : ComplexFloat>(argument : TNum, max_order : usize) -> Vec<TNum> {
+    spherical_jn(argument, max_order).iter().map(|j| argument * *j).collect()
+}
+
+/// The Riccati–Bessel functions ξ_n(z) = z h^(1)_n(z) for n = 0, ..., N, in the convention of Bohren and Huffman
+pub fn riccati_xi(argument : Complex64, max_order : usize) -> Vec<Complex64> {
+    spherical_h1n(argument, max_order).iter().map(|h| argument * h).collect()
+}
+
+// DLMF 10.51.1, from f_0 and f_1 up to f_N
+fn upward_recurrence<TNum : ComplexFloat>(argument : TNum, first : TNum, second : TNum, max_order : usize) -> Vec<TNum> {
+    let mut values = vec![first, second];
+    for n in 1..max_order {
+        let next = <TNum as NumCast>::from(2 * n + 1).unwrap() / argument * values[n] - values[n - 1];
+        values.push(next);
+    }
+
+    values.truncate(max_order + 1);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use num::complex::Complex64;
+
+    use super::{spherical_jn, spherical_yn, spherical_h1n, spherical_h2n, riccati_psi, riccati_xi};
+    use crate::special_functions::{jv, yv};
+
+    #[test]
+    fn agree_with_half_integer_order_cylinder_functions() {
+        // DLMF 10.47.3 and 10.47.4
+        for argument in [Complex64::new(0.05, 0.0), Complex64::new(3.0, 0.0), Complex64::new(40.0, 0.0), Complex64::new(2.0, -1.5), Complex64::new(-7.0, 4.0)] {
+            let j = spherical_jn(argument, 30);
+            let y = spherical_yn(argument, 30);
+            let prefactor = (std::f64::consts::PI / (2.0 * argument)).sqrt();
+            for n in 0..=30 {
+                let expected_j = prefactor * jv(argument, n as f64 + 0.5);
+                let expected_y = prefactor * yv(argument, n as f64 + 0.5);
+                println!("Order {n} at {argument}: {} against {expected_j}, {} against {expected_y}", j[n], y[n]);
+                assert!((j[n] - expected_j).norm() <= 1e-12 * expected_j.norm());
+                assert!((y[n] - expected_y).norm() <= 1e-12 * expected_y.norm());
+            }
+        }
+    }
+
+    #[test]
+    fn real_argument_values() {
+        let j = spherical_jn(1.0, 200);
+        assert!((j[1] - (1.0f64.sin() - 1.0f64.cos())).abs() < 1e-16);
+        // From mpmath
+        assert!((j[20] - 7.537795722236873e-26).abs() < 1e-12 * 7.537795722236873e-26);
+        assert_eq!(j[200], 0.0);
+        assert_eq!(spherical_jn(0.0, 3), vec![1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(spherical_yn(2.5, 5).len(), 6);
+    }
+
+    #[test]
+    fn hankel_functions_combine_both_kinds() {
+        for argument in [Complex64::new(0.5, 0.0), Complex64::new(12.0, 0.3), Complex64::new(-3.0, -2.0)] {
+            let j = spherical_jn(argument, 15);
+            let y = spherical_yn(argument, 15);
+            let h1 = spherical_h1n(argument, 15);
+            let h2 = spherical_h2n(argument, 15);
+            for n in 0..=15 {
+                assert!((h1[n] - (j[n] + Complex64::i() * y[n])).norm() <= 1e-12 * h1[n].norm());
+                assert!((h2[n] - (j[n] - Complex64::i() * y[n])).norm() <= 1e-12 * h2[n].norm());
+            }
+        }
+    }
+
+    #[test]
+    fn riccati_bessel_functions_satisfy_wronskian() {
+        // ψ_n ξ'_n - ψ'_n ξ_n = i, using ψ'_n = ψ_n-1 - n ψ_n / x and likewise for ξ
+        let argument = 8.5;
+        let psi = riccati_psi(argument, 25);
+        let xi = riccati_xi(Complex64::new(argument, 0.0), 25);
+        for n in 1..=25 {
+            let psi_derivative = psi[n - 1] - n as f64 * psi[n] / argument;
+            let xi_derivative = xi[n - 1] - n as f64 * xi[n] / argument;
+            let wronskian = psi[n] * xi_derivative - psi_derivative * xi[n];
+            assert!((wronskian - Complex64::i()).norm() < 1e-12, "{wronskian}");
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::f64::consts::PI;
+use num::complex::Complex64;
+
+use super::elliptic_k;
+use super::complete_elliptic_k::k_of_complementary_parameter;
+
+const THETA_MAX_TERMS : i32 = 1000;
+
+/// Jacobi theta function θ1(z, q) (DLMF 20.2.1) of the nome q, with q^(1/4) on its principal branch.
+/// It is summed from its Fourier series, which converges for |q| < 1 but loses accuracy to cancellation as |q| approaches 1.
+pub fn theta_1(z : Complex64, q : Complex64) -> Complex64 {
+    half_integer_series(z, q, true)
+}
+
+/// Jacobi theta function θ2(z, q) (DLMF 20.2.2) of the nome q, with q^(1/4) on its principal branch
+pub fn theta_2(z : Complex64, q : Complex64) -> Complex64 {
+    half_integer_series(z, q, false)
+}
+
+/// Jacobi theta function θ3(z, q) (DLMF 20.2.3) of the nome q
+pub fn theta_3(z : Complex64, q : Complex64) -> Complex64 {
+    integer_series(z, q, false)
+}
+
+/// Jacobi theta function θ4(z, q) (DLMF 20.2.4) of the nome q
+pub fn theta_4(z : Complex64, q : Complex64) -> Complex64 {
+    integer_series(z, q, true)
+}
+
+/// Nome q(m) = exp(-π K'(m) / K(m)) (DLMF 22.2.1) of the parameter m = k^2, with K taken from above its cut for real m > 1
+pub fn q(m : Complex64) -> Complex64 {
+    // K' and K diverge at either end
+    if m == Complex64::new(0.0, 0.0) || m == Complex64::new(1.0, 0.0) {
+        return m;
+    }
+
+    (-PI * k_of_complementary_parameter(m) / elliptic_k(m)).exp()
+}
+
+// The terms grow with n up to about this before they decay, as |q|^(n^2) e^(2 n |Im z|)
+fn largest_term(z : Complex64, q : Complex64) -> f64 {
+    z.im.abs() / -q.norm().ln()
+}
+
+// 2 Σ (±1)^n q^((n + 1/2)^2) sin or cos((2n + 1) z) over n >= 0, alternating with the sine for θ1
+fn half_integer_series(z : Complex64, q : Complex64, sine : bool) -> Complex64 {
+    if q.norm() >= 1.0 {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if q == Complex64::new(0.0, 0.0) {
+        return q;
+    }
+
+    let peak = largest_term(z, q);
+    let q_squared = q * q;
+    let mut power = (q.ln() / 4.0).exp();
+    let mut ratio = q_squared;
+    let mut sum = Complex64::new(0.0, 0.0);
+    for n in 0..THETA_MAX_TERMS {
+        let angle = (2 * n + 1) as f64 * z;
+        let term = if !sine {
+            power * angle.cos()
+        }
+        else if n % 2 == 0 {
+            power * angle.sin()
+        }
+        else {
+            -power * angle.sin()
+        };
+        sum += term;
+
+        if n as f64 > peak && term.norm() <= f64::EPSILON * sum.norm() {
+            break;
+        }
+        power *= ratio;
+        ratio *= q_squared;
+    }
+
+    2.0 * sum
+}
+
+// 1 + 2 Σ (±1)^n q^(n^2) cos(2n z) over n >= 1, alternating for θ4
+fn integer_series(z : Complex64, q : Complex64, alternating : bool) -> Complex64 {
+    if q.norm() >= 1.0 {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+
+    let peak = largest_term(z, q);
+    let q_squared = q * q;
+    let mut power = Complex64::new(1.0, 0.0);
+    let mut ratio = q;
+    let mut sum = Complex64::new(0.0, 0.0);
+    for n in 1..=THETA_MAX_TERMS {
+        power *= ratio;
+        ratio *= q_squared;
+        let cosine = (2.0 * n as f64 * z).cos();
+        let term = if alternating && n % 2 == 1 { -power * cosine } else { power * cosine };
+        sum += term;
+
+        if n as f64 > peak && term.norm() <= f64::EPSILON * (1.0 + 2.0 * sum).norm() {
+            break;
+        }
+    }
+
+    1.0 + 2.0 * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{theta_1, theta_2, theta_3, theta_4, q};
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_reference_values() {
+        let references = [
+            (c(0.5, 0.0), c(0.3, 0.0), c(0.5773940463248446, 0.0), c(1.3075255735032947, 0.0), c(1.317400827096804, 0.0), c(0.6691160041441827, 0.0)),
+            (c(0.5, 0.7), c(0.3, 0.2), c(0.5704267403900317, 0.3578142400849367), c(2.5906860801697267, -0.39674251956962286), c(2.5942368379283964, -0.3965935220702402), c(-0.0731869199807369, 0.5862781849972161)),
+            (c(1.0, 2.0), c(0.0, 0.5), c(291.83542862301897, -308.7064620304942), c(307.5124783926142, -367.6874226329259), c(-212.05309367150684, 288.781541933737), c(-68.31398038886284, -274.6650670146809)),
+            (c(2.0, 3.0), c(0.1, 0.0), c(22.027219034409995, -49.983335656426874), c(37.49770397773295, 3.4864736083045273), c(-27.682705633333732, 14.464356784540987), c(24.946535028847364, -46.66891427163599)),
+            (c(-1.5, 0.2), c(-0.4, 0.3), c(-1.7151135714579564, -0.6504189991765921), c(-0.36035267461083104, 0.1756001327537042), c(1.6762119266993063, -0.7476889887825362), c(0.019708642284675174, 0.6275589799914251)),
+        ];
+
+        for (z, nome, theta_1_value, theta_2_value, theta_3_value, theta_4_value) in references {
+            println!("z = {z}, q = {nome}: {} {} {} {}", theta_1(z, nome), theta_2(z, nome), theta_3(z, nome), theta_4(z, nome));
+            assert!((theta_1(z, nome) - theta_1_value).norm() <= 1e-14 * theta_1_value.norm());
+            assert!((theta_2(z, nome) - theta_2_value).norm() <= 1e-14 * theta_2_value.norm());
+            assert!((theta_3(z, nome) - theta_3_value).norm() <= 1e-14 * theta_3_value.norm());
+            assert!((theta_4(z, nome) - theta_4_value).norm() <= 1e-14 * theta_4_value.norm());
+        }
+    }
+
+    #[test]
+    fn nome_and_identities() {
+        let references = [
+            (c(0.5, 0.0), c(0.04321391826377225, 0.0)),
+            (c(0.01, 0.0), c(0.0006281456603830156, 0.0)),
+            (c(1e-8, 0.0), c(6.250000031250001e-10, 0.0)),
+            (c(1e-12, 0.0), c(6.250000000003125e-14, 0.0)),
+            (c(0.999, 0.0), c(0.3607378778859829, 0.0)),
+            (c(-3.0, 0.0), c(-0.08579573370219476, 0.0)),
+            (c(0.3, 0.4), c(0.013515607886731083, 0.032468571123544426)),
+        ];
+
+        for (m, expected) in references {
+            println!("m = {m}: {} against {expected}", q(m));
+            assert!((q(m) - expected).norm() <= 1e-14 * expected.norm());
+        }
+
+        assert_eq!(q(c(0.0, 0.0)), c(0.0, 0.0));
+        let zero = c(0.0, 0.0);
+        for nome in [c(0.1, 0.0), c(0.6, 0.0), c(0.2, -0.5)] {
+            // DLMF 20.7.3, θ3(0)^4 = θ2(0)^4 + θ4(0)^4
+            let lhs = theta_3(zero, nome).powi(4);
+            let rhs = theta_2(zero, nome).powi(4) + theta_4(zero, nome).powi(4);
+            assert!((lhs - rhs).norm() <= 1e-14 * lhs.norm());
+            // DLMF 20.2.11, θ1(z + π/2) = θ2(z)
+            let z = c(0.4, 0.3);
+            assert!((theta_2(z, nome) - theta_1(z + FRAC_PI_2, nome)).norm() <= 1e-14 * theta_2(z, nome).norm());
+        }
+
+        assert_eq!(theta_1(c(0.7, 0.0), c(0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(theta_3(c(0.7, 0.0), c(0.0, 0.0)), c(1.0, 0.0));
+        assert!(theta_3(c(0.7, 0.0), c(1.0, 0.0)).re.is_nan());
+    }
+}
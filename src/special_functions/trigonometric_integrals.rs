@@ -1,258 +1,218 @@
-/// This entire document uses https://link.springer.com/article/10.1007/bf02142806 for numerics
+use std::f64::consts::{FRAC_PI_2, PI};
+use num::{complex::{Complex64, ComplexFloat}, NumCast};
 
-use std::f64::consts::PI;
-use num::{complex::ComplexFloat, Float, NumCast};
-use crate::{calculators::polynomial_ratio, EM_GAMMA};
+use crate::{asymptotic_series, iterative_calculation, EM_GAMMA};
+use super::{ein, e1, to_complex64, from_complex64};
 
+// Within this the power series are summed directly, and beyond it E1 has no cancellation against ln z
+const SERIES_RADIUS : f64 = 2.0;
+// Beyond this the asymptotic expansions of f and g reach machine precision before they diverge
+const ASYMPTOTIC_RADIUS : f64 = 40.0;
+const ASYMPTOTIC_MAX_TERMS : i32 = 100;
 
-/// Sine integral Si (DLMF 6.2.9)
+/// Sine integral Si (DLMF 6.2.9), an odd entire function
 pub fn capital_si<TNum : ComplexFloat>(x : TNum) -> TNum {
-    let input_norm = x.abs();
-    let input_norm_squared = x.abs() * x.abs();
-
-    // Begin with limiting cases where machine epsilon is relevant and simplifies things
-    if input_norm_squared < TNum::Real::epsilon() * NumCast::from(18).unwrap() {
-        return x;
-    }
-
-    if input_norm > <TNum::Real as NumCast>::from(2).unwrap() / TNum::Real::epsilon() {
-        return NumCast::from(PI / 2.0).unwrap();
-    }
+    from_complex64(complex_si(to_complex64(x)))
+}
 
-    if input_norm_squared > <TNum::Real as NumCast>::from(6).unwrap() / TNum::Real::epsilon() {
-        return <TNum as NumCast>::from(PI / 2.0).unwrap() - x.cos() / x - x.sin() / (x * x);
-    }
+/// Cosine integral Ci (DLMF 6.2.11) on its principal branch, with the cut along the negative real axis where Ci(-x ± i0) = Ci(x) ± iπ.
+/// Real types give NaN for negative arguments, where the value is complex.
+pub fn ci<TNum : ComplexFloat>(x : TNum) -> TNum {
+    from_complex64(complex_ci(to_complex64(x)))
+}
 
-    if input_norm < NumCast::from(6).unwrap() {
-        return x * polynomial_ratio(&SI_NUMERATOR, &SI_DENOMINATOR, x * x).0;
-    }
+/// Complementary cosine integral Cin (DLMF 6.2.12), an even entire function
+pub fn cin<TNum : ComplexFloat>(x : TNum) -> TNum {
+    from_complex64(complex_cin(to_complex64(x)))
+}
 
-    let sign_flipped = if x.re().is_sign_negative() { -x } else { x };
-    let sine_sign_flipped = <TNum as NumCast>::from(PI / 2.0).unwrap() - f(sign_flipped) * sign_flipped.cos() - g(sign_flipped) * sign_flipped.sin();
+/// Hyperbolic sine integral Shi (DLMF 6.2.15), an odd entire function
+pub fn shi<TNum : ComplexFloat>(x : TNum) -> TNum {
+    // The odd terms of the power series of Ein (DLMF 6.6.4) are those of Shi
+    (ein(x) - ein(-x)) / NumCast::from(2).unwrap()
+}
 
-    return if x.re().is_sign_negative() { -sine_sign_flipped } else { sine_sign_flipped };
+/// Hyperbolic cosine integral Chi (DLMF 6.2.16), with the branch cut of ln x along the negative real axis
+pub fn chi<TNum : ComplexFloat>(x : TNum) -> TNum {
+    // The even terms of the power series of Ein (DLMF 6.6.4) are those of Chi - γ - ln x with their sign flipped
+    <TNum as NumCast>::from(EM_GAMMA).unwrap() + x.ln() - (ein(x) + ein(-x)) / NumCast::from(2).unwrap()
 }
 
-// Cosine integral, not defined for negative real values
-pub fn ci<TNum : ComplexFloat>(x : TNum) -> TNum {
-    let x_abs = x.abs();
+/// Auxiliary trigonometric integral f (DLMF 6.2.17), sharing the branch cut of Ci.
+/// Real types give NaN for negative arguments, where the value is complex.
+pub fn f<TNum : ComplexFloat>(x : TNum) -> TNum {
+    from_complex64(complex_auxiliary(to_complex64(x)).0)
+}
 
-    if x_abs < <TNum::Real as NumCast>::from(2).unwrap() * num::Float::sqrt(<TNum as ComplexFloat>::Real::epsilon()) {
-        return <TNum as NumCast>::from(EM_GAMMA).unwrap() + x.ln();
-    }
+/// Auxiliary trigonometric integral g (DLMF 6.2.18), sharing the branch cut of Ci.
+/// Real types give NaN for negative arguments, where the value is complex.
+pub fn g<TNum : ComplexFloat>(x : TNum) -> TNum {
+    from_complex64(complex_auxiliary(to_complex64(x)).1)
+}
 
-    if x_abs > <TNum::Real as NumCast>::from(PI).unwrap() / TNum::Real::epsilon() {
-        return TNum::zero();
-    }
+// The odd terms of the power series of Ein(iz) (DLMF 6.6.4) are those of i Si(z), and Ein is accurate on the whole plane
+fn complex_si(z : Complex64) -> Complex64 {
+    let i = Complex64::new(0.0, 1.0);
+    (ein(i * z) - ein(-i * z)) / (2.0 * i)
+}
 
-    if x_abs < NumCast::from(3).unwrap() {
-        let ci_r0_cast = <TNum as NumCast>::from(CI_R0).unwrap();
-        return (x / ci_r0_cast).ln() + (x * x - ci_r0_cast * ci_r0_cast) * polynomial_ratio(&CI_NUMERATOR_LEQ_3, &CI_DENOMINATOR_LEQ_3, x * x).0;
+fn complex_cin(z : Complex64) -> Complex64 {
+    if z.norm() < f64::EPSILON {
+        return z * z / 4.0;
     }
 
-    if x_abs < NumCast::from(6).unwrap() {
-        let ci_r1_cast = <TNum as NumCast>::from(CI_R1).unwrap();
-        return (x / ci_r1_cast).ln() + (x * x - ci_r1_cast * ci_r1_cast) * polynomial_ratio(&CI_NUMERATOR_G_3_LEQ_6, &CI_DENOMINATOR_G_3_LEQ_6, x * x).0;
+    if z.norm() < SERIES_RADIUS {
+        // DLMF 6.6.6 with DLMF 6.2.13, Cin(z) = -sum_(k >= 1) (-z^2)^k / (2k (2k)!)
+        let mut power = Complex64::new(-1.0, 0.0);
+        return iterative_calculation(f64::EPSILON, 1, 1, |k| {
+            power *= -z * z / ((2 * k - 1) * 2 * k) as f64;
+            power / (2 * k) as f64
+        });
     }
 
-    return f(x) * x.sin() - g(x) * x.cos();
+    // The even terms of the power series of Ein(iz) (DLMF 6.6.4) are those of Cin(z)
+    let i = Complex64::new(0.0, 1.0);
+    (ein(i * z) + ein(-i * z)) / 2.0
 }
 
-/// Complementary cosine integral Cin (DLMF 6.2.12)
-pub fn cin<TNum : ComplexFloat>(x : TNum) -> TNum {
-    let x_pos = if x.re().is_sign_negative() { -x } else { x };
-    return -ci(x_pos) + x_pos.ln() + NumCast::from(EM_GAMMA).unwrap()
-}
-
-/// Auxiliary trigonometric integral, not defined for negative real values
-pub fn f<TNum : ComplexFloat>(x : TNum) -> TNum {
-    if x.abs() < <TNum::Real as NumCast>::from(6).unwrap() {
-        return ci(x) * x.sin() - (capital_si(x) - NumCast::from(PI / 2.0).unwrap()) * x.cos()
+fn complex_ci(z : Complex64) -> Complex64 {
+    if z.re < 0.0 {
+        // From DLMF 6.2.13, as Cin is even and ln z changes by ±iπ under z -> -z
+        return complex_ci(-z) + Complex64::new(0.0, PI.copysign(z.im));
     }
 
-    if x.abs() <= NumCast::from(12.0).unwrap() {
-        return polynomial_ratio(&F_NUMERATOR_SMALL, &F_DENOMINATOR_SMALL, TNum::one() / (x * x)).0 / x;
+    if z.norm() < SERIES_RADIUS {
+        // DLMF 6.2.13
+        return EM_GAMMA + z.ln() - complex_cin(z);
     }
 
-    return (TNum::one() - polynomial_ratio(&F_NUMERATOR_BIG, &F_DENOMINATOR_BIG, TNum::one() / (x * x)).0 / (x * x)) / x;
+    // DLMF 6.5.5 and 6.5.6 added, for |ph z| <= π / 2
+    let i = Complex64::new(0.0, 1.0);
+    let minus_i = Complex64::new(0.0, -1.0);
+    -(e1(i * z) + e1(minus_i * z)) / 2.0
 }
 
-// Auxiliary trigonometric integral, not defined for negative real values
-pub fn g<TNum : ComplexFloat>(x : TNum) -> TNum {
-    if x.abs() < NumCast::from(6).unwrap() {
-        return -ci(x) * x.cos() - (capital_si(x) - NumCast::from(PI / 2.0).unwrap()) * x.sin();
+// f(z) and g(z) together
+fn complex_auxiliary(z : Complex64) -> (Complex64, Complex64) {
+    if z == Complex64::new(0.0, 0.0) {
+        return (Complex64::new(FRAC_PI_2, 0.0), Complex64::new(f64::INFINITY, 0.0));
     }
 
-    if x.abs() <= NumCast::from(12.0).unwrap() {
-        return polynomial_ratio(&G_NUMERATOR_SMALL, &G_DENOMINATOR_SMALL, TNum::one() / (x * x)).0 / (x * x);
+    if z.re < 0.0 {
+        // From DLMF 6.2.17 and 6.2.18, with Ci(z) = Ci(-z) ± iπ and Si(z) = -Si(-z) for ±Im z >= 0
+        let sign = 1.0_f64.copysign(z.im);
+        let (f, g) = complex_auxiliary(-z);
+        let phase = Complex64::new(0.0, sign) * z;
+        return (PI * phase.exp() - f, g - Complex64::new(0.0, sign * PI) * phase.exp());
     }
 
-    return (TNum::one() - polynomial_ratio(&G_NUMERATOR_BIG, &G_DENOMINATOR_BIG, TNum::one() / (x * x)).0 / (x * x)) / (x * x);
-}
+    if z.norm() >= ASYMPTOTIC_RADIUS {
+        // DLMF 6.12.3 and 6.12.4
+        let inverse_square = 1.0 / (z * z);
+        let mut term = Complex64::new(1.0, 0.0);
+        let f_sum = asymptotic_series(f64::EPSILON, 0, ASYMPTOTIC_MAX_TERMS, |m| {
+            if m > 0 {
+                term *= -inverse_square * ((2 * m - 1) * 2 * m) as f64;
+            }
+            term
+        }).value;
+        let mut term = Complex64::new(1.0, 0.0);
+        let g_sum = asymptotic_series(f64::EPSILON, 0, ASYMPTOTIC_MAX_TERMS, |m| {
+            if m > 0 {
+                term *= -inverse_square * (2 * m * (2 * m + 1)) as f64;
+            }
+            term
+        }).value;
+        return (f_sum / z, g_sum * inverse_square);
+    }
 
-const CI_R0 : f64 = 0.616505485620716233797110404100;
-const CI_R1 : f64 = 3.384180422851186426397851146402;
-
-const SI_NUMERATOR : [f64; 8] = [
-    1.00000000000000000000E0,
-    -0.44663998931312457298E-1,
-    0.11209146443112369449E-2,
-    -0.13276124407928422367E-4,
-    0.85118014179823463879E-7,
-    -0.29989314303147656479E-9,
-    0.55401971660186204711E-12,
-    -0.42406353433133212926E-15,
-];
-
-const SI_DENOMINATOR : [f64; 8] = [
-    1.00000000000000000000E0,
-    0.10891556624243098264E-1,
-    0.59334456769186835896E-4,
-    0.21231112954641805908E-6,
-    0.54747121846510390750E-9,
-    0.10378561511331814674E-11,
-    0.13754880327250272679E-14,
-    0.10223981202236205703E-17
-];
-
-const CI_NUMERATOR_LEQ_3 : [f64; 6] = [
-    -0.24607411378767540707E0,
-    0.72113492241301534559E-2,
-    -0.11867127836204767056E-3,
-    0.90542655466969866243E-6,
-    -0.34322242412444409037E-8,
-    0.51950683460656886834E-11,
-];
-
-const CI_DENOMINATOR_LEQ_3 : [f64; 6] = [
-    1.00000000000000000000,
-    0.12670095552700637845E-1,
-    0.78168450570724148921E-4,
-    0.29959200177005821677E-6,
-    0.73191677761328838216E-9,
-    0.94351174530907529061E-12,
-];
-
-const CI_NUMERATOR_G_3_LEQ_6 : [f64; 8] = [
-    -0.15684781827145408780E0,
-    0.66253165609605468916E-2,
-    -0.12822297297864512864E-3,
-    0.12360964097729408891E-5,
-    -0.66450975112876224532E-8,
-    0.20326936466803159446E-10,
-    -0.33590883135343844613E-13,
-    0.23686934961435015119E-16,
-];
-
-const CI_DENOMINATOR_G_3_LEQ_6 : [f64; 8] = [
-    1.00000000000000000000E0,
-    0.96166044388828741188E-2,
-    0.45257514591257035006E-4,
-    0.13544922659627723233E-6,
-    0.27715365686570002081E-9,
-    0.37718676301688932926E-12,
-    0.27706844497155995398E-15,
-    0.00000000000000000000E0,
-];
-
-const F_NUMERATOR_SMALL : [f64; 8] = [
-    0.99999999962173909991, 
-    0.36451060338631902917e3, 
-    0.44218548041288440874e5,
-    0.22467569405961151887e7, 
-    0.49315316723035561922e8, 
-    0.43186795279670283193e9,
-    0.11847992519956804350e10, 
-    0.45573267593795103181e9,
-];
-
-const F_DENOMINATOR_SMALL : [f64; 8] = [
-    1.0, 
-    0.36651060273229347594e3, 
-    0.44927569814970692777e5, 
-    0.23285354882204041700e7,
-    0.53117852017228262911e8, 
-    0.50335310667241870372e9, 
-    0.16575285015623175410e10,
-    0.11746532837038341076e10,
-];
-
-const G_NUMERATOR_SMALL : [f64; 9] = [
-    0.99999999920484901956, 
-    0.51385504875307321394e3, 
-    0.92293483452013810811e5,
-    0.74071341863359841727e7, 
-    0.28142356162841356551e9, 
-    0.49280890357734623984e10,
-    0.35524762685554302472e11, 
-    0.79194271662085049376e11, 
-    0.17942522624413898907e11,
-];
-
-const G_DENOMINATOR_SMALL : [f64; 9] = [
-    1.0, 
-    0.51985504708814870209e3, 
-    0.95292615508125947321e5, 
-    0.79215459679762667578e7,
-    0.31977567790733781460e9, 
-    0.62273134702439012114e10, 
-    0.54570971054996441467e11,
-    0.18241750166645704670e12, 
-    0.15407148148861454434e12,
-];
-
-const F_NUMERATOR_BIG : [f64; 8] = [
-    0.19999999999999978257e1, 
-    0.22206119380434958727e4, 
-    0.84749007623988236808e6,
-    0.13959267954823943232e9, 
-    0.10197205463267975592e11, 
-    0.30229865264524075951e12,
-    0.27504053804288471142e13, 
-    0.21818989704686874983e13,
-];
-
-const F_DENOMINATOR_BIG : [f64; 8] = [
-    1.0, 
-    0.11223059690217167788e4, 
-    0.43685270974851313242e6, 
-    0.74654702140658116258e8,
-    0.58580034751805687471e10, 
-    0.20157980379272098841e12, 
-    0.26229141857684496445e13,
-    0.87852907334918467516e13,
-];
-
-const G_NUMERATOR_BIG : [f64; 9] = [
-    0.59999999999999993089e1, 
-    0.96527746044997139158e4, 
-    0.56077626996568834185e7,
-    0.15022667718927317198e10, 
-    0.19644271064733088465e12, 
-    0.12191368281163225043e14,
-    0.31924389898645609533e15, 
-    0.25876053010027485934e16, 
-    0.12754978896268878403e16,
-];
-
-const G_DENOMINATOR_BIG : [f64; 9] = [
-    1.0, 
-    0.16287957674166143196e4, 
-    0.96636303195787870963e6, 
-    0.26839734750950667021e9,
-    0.37388510548029219241e11, 
-    0.26028585666152144496e13, 
-    0.85134283716950697226e14,
-    0.11304079361627952930e16, 
-    0.42519841479489798424e16,
-];
+    // From DLMF 6.5.5 and 6.5.6, e^(±iz) E1(±iz) = g(z) ∓ i f(z) for |ph z| <= π / 2
+    let i = Complex64::new(0.0, 1.0);
+    let minus_i = Complex64::new(0.0, -1.0);
+    let plus = (i * z).exp() * e1(i * z);
+    let minus = (minus_i * z).exp() * e1(minus_i * z);
+    ((minus - plus) / (2.0 * i), (plus + minus) / 2.0)
+}
 
 #[cfg(test)]
 mod tests {
     extern crate num;
 
-    use super::{capital_si,cin};
+    use std::f64::consts::PI;
+
+    use super::{capital_si, cin, ci, shi, chi, f, g};
     use num::complex::{Complex64, ComplexFloat};
+    use crate::EM_GAMMA;
+    use crate::special_functions::test_support::c;
+
+    #[test]
+    fn matches_reference_grid() {
+        // From mpmath, as z, Si(z), Ci(z), f(z) and g(z), at four radii and four phases in each half plane
+        let references = [
+            (c(0.669, 0.207), c(0.6571642377071687, 0.1923299011381947), c(0.12069409707106248, 0.2331410079721924), c(0.7455688099468715, -0.10480683685314937), c(0.4834150872548996, -0.14364325633877204)),
+            (c(0.669, -0.207), c(0.6571642377071687, -0.1923299011381947), c(0.12069409707106248, -0.2331410079721924), c(0.7455688099468715, 0.10480683685314937), c(0.4834150872548996, 0.14364325633877204)),
+            (c(0.254, 0.652), c(0.271355042354224, 0.6603064266994589), c(0.31049468457029433, 1.1139993564471335), c(0.7599793833937626, -0.45645931499175624), c(0.2828563113043843, -0.5841795890268221)),
+            (c(0.254, -0.652), c(0.271355042354224, -0.6603064266994589), c(0.31049468457029433, -1.1139993564471335), c(0.7599793833937626, 0.45645931499175624), c(0.2828563113043843, 0.5841795890268221)),
+            (c(-0.291, 0.637), c(-0.30954721011453906, 0.6422151556274777), c(0.3008907645915019, 2.0944927832447453), c(0.8339923309588891, -0.907687773950662), c(-0.17327105908205764, -1.0367170439433082)),
+            (c(-0.291, -0.637), c(-0.30954721011453906, -0.6422151556274777), c(0.3008907645915019, -2.0944927832447453), c(0.8339923309588891, 0.907687773950662), c(-0.17327105908205764, 1.0367170439433082)),
+            (c(-0.68, 0.167), c(-0.6657931468186151, 0.15464818160435842), c(0.11363363205771451, 2.955521467787189), c(1.3217080518059403, -1.755538624028623), c(-1.1836272085711403, -1.9518575289793396)),
+            (c(-0.68, -0.167), c(-0.6657931468186151, -0.15464818160435842), c(0.11363363205771451, -2.955521467787189), c(1.3217080518059403, 1.755538624028623), c(-1.1836272085711403, 1.9518575289793396)),
+            (c(3.821, 1.182), c(1.9027353771799789, -0.26617700787376597), c(-0.27972847062158535, -0.26452817821487257), c(0.22313032610558198, -0.05952322504897339), c(0.04421909638338113, -0.02435698536733048)),
+            (c(3.821, -1.182), c(1.9027353771799789, 0.26617700787376597), c(-0.27972847062158535, 0.26452817821487257), c(0.22313032610558198, 0.05952322504897339), c(0.04421909638338113, 0.02435698536733048)),
+            (c(1.449, 3.728), c(6.491820062449748, 4.345151593714792), c(4.346106098001546, -4.925927368508454), c(0.12141339225531639, -0.2289986897334254), c(-0.03136279325953368, -0.05722260863087143)),
+            (c(1.449, -3.728), c(6.491820062449748, -4.345151593714792), c(4.346106098001546, 4.925927368508454), c(0.12141339225531639, 0.2289986897334254), c(-0.03136279325953368, 0.05722260863087143)),
+            (c(-1.665, 3.637), c(-6.510757752229826, 3.180512430467211), c(3.1829375023122974, 8.086473048245193), c(-0.14048900386128785, -0.3013606395157265), c(-0.1067144304239063, 0.06637092241689153)),
+            (c(-1.665, -3.637), c(-6.510757752229826, -3.180512430467211), c(3.1829375023122974, -8.086473048245193), c(-0.14048900386128785, 0.3013606395157265), c(-0.1067144304239063, -0.06637092241689153)),
+            (c(-3.884, 0.957), c(-1.845539959588429, -0.20407170247444228), c(-0.230795583763033, 3.333766507445974), c(-1.1142581735964565, 0.7677209513786161), c(0.8618018067542699, 0.9087916896303353)),
+            (c(-3.884, -0.957), c(-1.845539959588429, 0.20407170247444228), c(-0.230795583763033, -3.333766507445974), c(-1.1142581735964565, -0.7677209513786161), c(0.8618018067542699, -0.9087916896303353)),
+            (c(23.883, 7.388), c(-16.36500160580089, -27.274451775241612), c(-27.274476184216265, 17.93579831529009), c(0.038134665259604746, -0.011723256560264825), c(0.001314913818236017, -0.0008896088740040899)),
+            (c(23.883, -7.388), c(-16.36500160580089, 27.274451775241612), c(-27.274476184216265, -17.93579831529009), c(0.038134665259604746, 0.011723256560264825), c(0.001314913818236017, 0.0008896088740040899)),
+            (c(9.059, 23.301), c(187277112.0750877, -199824130.7068469), c(-199824130.7068469, -187277110.50429136), c(0.014611553825116603, -0.0373373188917253), c(-0.0011808312951225467, -0.0010964356654963297)),
+            (c(9.059, -23.301), c(187277112.0750877, 199824130.7068469), c(-199824130.7068469, 187277110.50429136), c(0.014611553825116603, 0.0373373188917253), c(-0.0011808312951225467, 0.0010964356654963297)),
+            (c(-10.404, 22.732), c(78446022.44516337, -133513532.63937868), c(-133513532.63937868, -78446020.87436704), c(-0.016771722645923498, -0.03640625136294187), c(-0.0010431209032510699, 0.0012263966643261714)),
+            (c(-10.404, -22.732), c(78446022.44516337, 133513532.63937868), c(-133513532.63937868, 78446020.87436704), c(-0.016771722645923498, 0.03640625136294187), c(-0.0010431209032510699, -0.0012263966643261714)),
+            (c(-24.274, 5.981), c(4.74442352364285, -4.869635975241192), c(-4.869726656470493, -3.173585287890554), c(-0.03355778406048803, -0.0034787680510463205), c(0.007416357633609576, -0.004454544956383119)),
+            (c(-24.274, -5.981), c(4.74442352364285, 4.869635975241192), c(-4.869726656470493, 3.173585287890554), c(-0.03355778406048803, 0.0034787680510463205), c(0.007416357633609576, 0.004454544956383119)),
+            (c(52.544, 16.254), c(87503.77461138062, 57416.5897356658), c(57416.589735667265, -87502.20381505323), c(0.017362078833544427, -0.00536373962644996), c(0.00027259235852465804, -0.00018605000146751992)),
+            (c(52.544, -16.254), c(87503.77461138062, -57416.5897356658), c(57416.589735667265, 87502.20381505323), c(0.017362078833544427, 0.00536373962644996), c(0.00027259235852465804, 0.00018605000146751992)),
+            (c(19.93, 51.262), c(1.0948428484371202e+20, 1.292763050172717e+20), c(1.292763050172717e+20, -1.0948428484371202e+20), c(0.006599261214229195, -0.01695143507403658), c(-0.0002438184843532082, -0.00022395357921666155)),
+            (c(19.93, -51.262), c(1.0948428484371202e+20, -1.292763050172717e+20), c(1.292763050172717e+20, 1.0948428484371202e+20), c(0.006599261214229195, 0.01695143507403658), c(-0.0002438184843532082, 0.00022395357921666155)),
+            (c(-22.888, 50.011), c(2.150229208259852e+19, -4.343483013399423e+19), c(-4.343483013399423e+19, -2.150229208259852e+19), c(-0.007577961934175672, -0.01653610942451698), c(-0.0002159834910218491, 0.0002508375644341146)),
+            (c(-22.888, -50.011), c(2.150229208259852e+19, 4.343483013399423e+19), c(-4.343483013399423e+19, 2.150229208259852e+19), c(-0.007577961934175672, 0.01653610942451698), c(-0.0002159834910218491, -0.0002508375644341146)),
+            (c(-53.403, 13.159), c(-4621.277935830237, -1031.3987432116987), c(-1031.398743202626, 4622.848732123344), c(-0.01765072750985875, -0.004342124849434571), c(0.000292331985499653, 0.00015910952059768426)),
+            (c(-53.403, -13.159), c(-4621.277935830237, 1031.3987432116987), c(-1031.398743202626, -4622.848732123344), c(-0.01765072750985875, 0.004342124849434571), c(0.000292331985499653, -0.00015910952059768426)),
+        ];
+
+        for (z, si_value, ci_value, f_value, g_value) in references {
+            println!("z = {z}: {} {} {} {}", capital_si(z), ci(z), f(z), g(z));
+            assert!((capital_si(z) - si_value).abs() <= 1e-14 * si_value.abs());
+            assert!((ci(z) - ci_value).abs() <= 1e-14 * ci_value.abs());
+            assert!((f(z) - f_value).abs() <= 1e-13 * f_value.abs());
+            assert!((g(z) - g_value).abs() <= 1e-13 * g_value.abs());
+            // DLMF 6.2.13
+            let cin_value = EM_GAMMA + z.ln() - ci_value;
+            assert!((cin(z) - cin_value).abs() <= 1e-14 * cin_value.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn branch_cut_of_ci() {
+        for x in [0.5, 3.0, 12.0, 80.0] {
+            let above = ci(c(-x, 0.0));
+            let below = ci(c(-x, -0.0));
+            assert!((above - ci(x) - c(0.0, PI)).abs() <= 1e-15 * above.abs());
+            assert!((below - ci(x) + c(0.0, PI)).abs() <= 1e-15 * below.abs());
+            assert!((f(c(-x, 0.0)) - f(c(-x, -0.0)).conj()).abs() <= 1e-15 * f(c(-x, 0.0)).abs());
+            assert_eq!(cin(-x), cin(x));
+            assert_eq!(capital_si(-x), -capital_si(x));
+        }
+
+        assert!(ci(-1.0f64).is_nan());
+        assert!(g(-1.0f64).is_nan());
+        assert_eq!(ci(0.0), f64::NEG_INFINITY);
+        assert_eq!(f(0.0), PI / 2.0);
+        assert_eq!(cin(0.0), 0.0);
+    }
 
     #[test]
     fn si_two() {
@@ -269,6 +229,43 @@ mod tests {
         assert!((cin(-5.0) - 2.37668) < 0.001);
     }
 
+    #[test]
+    fn shi_and_chi_reference_values() {
+        let references : [(f64, f64, f64); 4] = [
+            (0.3, 0.3015040562050104, -0.6041725954708363),
+            (1.0, 1.0572508753757286, 0.8378669409802082),
+            (4.5, 13.967885049347148, 13.965811648592434),
+            (20.0, 12807826.332028294, 12807826.332028294),
+        ];
+
+        for (x, shi_value, chi_value) in references {
+            assert!((shi(x) - shi_value).abs() <= 1e-14 * shi_value.abs());
+            assert!((chi(x) - chi_value).abs() <= 1e-14 * chi_value.abs());
+        }
+
+        let references = [
+            (Complex64::new(1.0, 1.0), Complex64::new(0.8824538050079177, 1.1042226582355816), Complex64::new(0.8821721805559364, 1.2835471932749407)),
+            (Complex64::new(-2.0, 0.5), Complex64::new(-2.3813808029061114, 0.889382292100898), Complex64::new(2.3443691418927504, 2.2217241449150977)),
+            (Complex64::new(0.5, -3.0), Complex64::new(0.019660093312929356, -1.8922457483081392), Complex64::new(0.11113294973243845, -1.7417984548450205)),
+            (Complex64::new(-7.0, -2.0), Complex64::new(6.88922813204502, -89.6295803872908), Complex64::new(-6.8891578556799935, 86.48807470163617)),
+        ];
+
+        for (z, shi_value, chi_value) in references {
+            assert!((shi(z) - shi_value).abs() <= 1e-14 * shi_value.abs());
+            assert!((chi(z) - chi_value).abs() <= 1e-14 * chi_value.abs());
+        }
+    }
+
+    #[test]
+    fn shi_and_chi_match_si_and_ci() {
+        // From DLMF 6.2.15 and 6.2.16, as Shi(z) = -i Si(iz) and Chi(z) = Ci(iz) - iπ/2 to the right of the imaginary axis
+        let i = Complex64::new(0.0, 1.0);
+        for z in [Complex64::new(0.5, 0.0), Complex64::new(1.0, 0.7), Complex64::new(2.5, -1.5), Complex64::new(0.3, 2.8), Complex64::new(4.0, 3.0)] {
+            assert!((shi(z) + i * capital_si(i * z)).abs() <= 1e-12 * shi(z).abs(), "Shi({z}) = {} against {}", shi(z), -i * capital_si(i * z));
+            assert!((chi(z) - ci(i * z) + i * PI / 2.0).abs() <= 1e-12 * chi(z).abs(), "Chi({z}) = {} against {}", chi(z), ci(i * z) - i * PI / 2.0);
+        }
+    }
+
     #[test]
     fn si_six_plus_two_i() {
         assert!((capital_si(Complex64::new(6.0, 2.0)) - Complex64::new(0.974993, -0.063689)).abs() < 0.001);